@@ -87,9 +87,11 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, MintTo, Transfer, Burn}
+    associated_token::{get_associated_token_address, AssociatedToken},
+    token::{self, Mint, Token, TokenAccount, MintTo, Transfer, Burn, CloseAccount, Approve}
 };
+use anchor_lang::solana_program::program_option::COption;
+use solana_program::pubkey;
 
 declare_id!("AGoRACoreXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
 
@@ -140,6 +142,9 @@ pub const LIVENESS_PERIOD_SECONDS: i64 = 31_536_000; // 365 days
 /// Grace period after liveness expiry
 pub const LIVENESS_GRACE_PERIOD: i64 = 2_592_000; // 30 days
 
+/// Period before liveness expiry in which users are warned
+pub const LIVENESS_WARNING_PERIOD: i64 = 5_184_000; // 60 days
+
 /// Governance program ID - used to verify sanction accounts
 /// This is the ONLY connection between Core and Governance
 pub const GOVERNANCE_PROGRAM_ID: Pubkey = pubkey!("AGoRAGovXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
@@ -148,6 +153,85 @@ pub const GOVERNANCE_PROGRAM_ID: Pubkey = pubkey!("AGoRAGovXXXXXXXXXXXXXXXXXXXXX
 /// Used to verify identity attestations from trusted issuers
 pub const SAS_PROGRAM_ID: Pubkey = pubkey!("22zoJMtdu4tQc2PzL74ZUT7FrwgB1Udec8DdW4yw4BdG");
 
+/// Number of graduated steps in a Governance CountrySanction schedule
+/// Must match MAX_SANCTION_STEPS in Governance (fixed account layout)
+pub const SANCTION_SCHEDULE_STEPS: usize = 4;
+
+/// Maximum citizenships a user can add beyond the one used at registration
+pub const MAX_ADDITIONAL_CITIZENSHIPS: usize = 2;
+
+/// Minimum time between citizenship changes or additions (365 days)
+/// Prevents switching or stacking countries to dodge sanctions
+pub const CITIZENSHIP_UPDATE_COOLDOWN: i64 = 31_536_000;
+
+/// SAS schema name for citizenship attestations. Each trusted issuer
+/// creates this schema under its own credential.
+pub const CITIZENSHIP_SCHEMA_NAME: &[u8] = b"agora_citizenship";
+
+/// SAS schema name for identity attestations that bind a wallet to a
+/// registered human (data carries the biometric hash)
+pub const IDENTITY_SCHEMA_NAME: &[u8] = b"agora_identity";
+
+/// SAS schema name for guardianship attestations issued to a guardian
+/// (data carries the child's wallet)
+pub const GUARDIANSHIP_SCHEMA_NAME: &[u8] = b"agora_guardianship";
+
+/// Version of the AGORA SAS schemas
+pub const ATTESTATION_SCHEMA_VERSION: u8 = 1;
+
+/// Delay before a wallet rotation can be completed (7 days)
+/// The old wallet can cancel during this period
+pub const WALLET_ROTATION_COOLDOWN: i64 = 604_800;
+
+/// Minimum / maximum number of social recovery guardians
+pub const MIN_RECOVERY_GUARDIANS: usize = 3;
+pub const MAX_RECOVERY_GUARDIANS: usize = 5;
+
+/// Period after guardian threshold during which the owner can veto (7 days)
+pub const RECOVERY_CHALLENGE_PERIOD: i64 = 604_800;
+
+/// Time after which a stalled pending recovery can be replaced by a new
+/// proposal (14 days, counted from the proposal or the end of the
+/// challenge period). Stops one guardian from blocking recovery.
+pub const RECOVERY_PROPOSAL_EXPIRY: i64 = 1_209_600;
+
+/// Bond to accuse two accounts of being the same human (1,000 AGORA)
+pub const FRAUD_CHALLENGE_BOND: u64 = 1_000_000_000_000;
+
+/// Maximum reward for an upheld fraud challenge, paid from forfeited bonds
+/// (500 AGORA)
+pub const FRAUD_CHALLENGE_REWARD: u64 = 500_000_000_000;
+
+/// Time a suspended user has to appeal (30 days)
+pub const SUSPENSION_APPEAL_WINDOW: i64 = 2_592_000;
+
+/// Suspension reason codes (stored in UserState.suspension_reason)
+pub const SUSPENSION_REASON_NONE: u8 = 0;
+pub const SUSPENSION_REASON_DUPLICATE_IDENTITY: u8 = 1;
+pub const SUSPENSION_REASON_FRAUD: u8 = 2;
+pub const SUSPENSION_REASON_ATTESTATION_REVOKED: u8 = 3;
+pub const SUSPENSION_REASON_DECEASED: u8 = 4;
+pub const SUSPENSION_REASON_OTHER: u8 = 255;
+
+/// Window for a guardian's child allowance (30 days).
+/// The cap per window is read from Governance CoreParameters.
+pub const CHILD_ALLOWANCE_PERIOD: i64 = 2_592_000;
+
+/// Hard maximum for the Governance dormant closure period (5 years).
+/// Longer values are treated as this maximum.
+pub const MAX_DORMANT_CLOSURE_PERIOD: i64 = 157_680_000;
+
+/// Hard maximum for the Governance allowance cap: one window of UBI
+/// (3,000 AGORA). Governance can lower it, never raise it.
+pub const MAX_CHILD_ALLOWANCE_MONTHLY_CAP: u64 = DAILY_AMOUNT * 30;
+
+/// Maximum users per `batch_claim` (bounded by compute and return data size)
+pub const MAX_BATCH_CLAIMS: usize = 8;
+
+/// Registration attempts beyond this multiple of an issuer's daily or
+/// weekly cap suspend the issuer until Governance sets new quotas
+pub const ISSUER_QUOTA_SUSPENSION_MULTIPLIER: u32 = 3;
+
 // Note: Trusted issuers (e.g., Civic) are managed by Governance program
 // Core reads TrustedIssuer accounts from Governance to verify attestations
 // This allows DAO to add/remove issuers without modifying immutable Core
@@ -256,6 +340,73 @@ pub mod agora_core {
         citizenship: [u8; 3],
     ) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
+        
+        // ====================================================================
+        // ATTESTATION & ISSUER QUOTA CHECK
+        // ====================================================================
+        // A compromised issuer could otherwise mint unlimited humans.
+        // An over-quota attestation is counted once (and may suspend the
+        // issuer): the first refusal is recorded in a RegistrationRefusal
+        // and refunds the new accounts; retries fail without counting.
+        
+        validate_attestation(
+            &ctx.accounts.attestation,
+            &ctx.accounts.trusted_issuer,
+            &ctx.accounts.owner.key(),
+            current_time,
+        )?;
+        
+        let stats = &mut ctx.accounts.issuer_stats;
+        if stats.credential == Pubkey::default() {
+            stats.credential = ctx.accounts.trusted_issuer_credential.key();
+            stats.bump = ctx.bumps.issuer_stats;
+        }
+        let first_refusal = matches!(
+            &ctx.accounts.registration_refusal,
+            Some(refusal) if refusal.attestation == Pubkey::default()
+        );
+        if !record_issuer_registration(stats, &ctx.accounts.trusted_issuer, current_time, first_refusal)? {
+            require!(first_refusal, CoreError::IssuerQuotaExceeded);
+            if let Some(refusal) = ctx.accounts.registration_refusal.as_mut() {
+                refusal.attestation = ctx.accounts.attestation.key();
+                refusal.issuer = stats.credential;
+                refusal.refused_at = current_time;
+                refusal.bump = ctx.bumps.registration_refusal;
+            }
+            
+            emit!(RegistrationRefused {
+                user: ctx.accounts.owner.key(),
+                issuer: stats.credential,
+                timestamp: current_time,
+            });
+            msg!("Registration refused: issuer quota exceeded");
+            
+            let owner = ctx.accounts.owner.to_account_info();
+            if let Some(escrow) = &ctx.accounts.child_escrow {
+                let bump = [ctx.accounts.protocol_state.bump];
+                let seeds: &[&[u8]] = &[b"protocol", &bump];
+                let signer_seeds = &[seeds];
+                let cpi_accounts = CloseAccount {
+                    account: escrow.to_account_info(),
+                    destination: owner.clone(),
+                    authority: ctx.accounts.protocol_state.to_account_info(),
+                };
+                token::close_account(CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                ))?;
+            }
+            ctx.accounts.user_state.close(owner.clone())?;
+            ctx.accounts.biometric_record.close(owner)?;
+            return Ok(());
+        }
+        
+        // A refusal record is no longer needed once the attestation registers
+        if let Some(refusal) = &ctx.accounts.registration_refusal {
+            refusal.close(ctx.accounts.owner.to_account_info())?;
+        }
+        
         let protocol = &mut ctx.accounts.protocol_state;
         let user = &mut ctx.accounts.user_state;
         let registry = &mut ctx.accounts.biometric_registry;
@@ -294,6 +445,29 @@ pub mod agora_core {
         user.attestation_updated_at = current_time;
         user.attestation_update_count = 0;
         
+        user.additional_citizenships = [[0u8; 3]; MAX_ADDITIONAL_CITIZENSHIPS];
+        user.additional_citizenship_count = 0;
+        user.citizenship_updated_at = current_time;
+        user.citizenship_update_count = 0;
+        
+        user.suspension_reason = SUSPENSION_REASON_NONE;
+        user.suspended_at = 0;
+        user.appeal_deadline = 0;
+        user.appealed_at = 0;
+        user.last_order_proposal_id = 0;
+        user.attestation_lapsed_at = 0;
+        user.attestation_issuer = read_attestation_credential(&ctx.accounts.attestation);
+        
+        user.guardian = Pubkey::default();
+        user.allowance_period_start = 0;
+        user.allowance_withdrawn = 0;
+        user.claim_delegate = Pubkey::default();
+        user.last_delegated_claim_at = 0;
+        user.liveness_warned_at = 0;
+        user.heir = Pubkey::default();
+        user.dormant_since = 0;
+        user.previous_user_state = Pubkey::default();
+        
         user.bump = ctx.bumps.user_state;
         
         // ====================================================================
@@ -306,6 +480,7 @@ pub mod agora_core {
         bio_record.is_registered = true;
         bio_record.invalidated_at = 0;
         bio_record.previous_hash = None;
+        bio_record.is_retired = false;
         bio_record.bump = ctx.bumps.biometric_record;
         
         registry.total_registered += 1;
@@ -327,26 +502,11 @@ pub mod agora_core {
         // CHECK FOR COUNTRY SANCTION
         // ====================================================================
         // We read the sanction account from Governance program.
-        // If it exists and is active, reduce the claim proportionally.
-        
-        let sanction_percentage = match &ctx.accounts.country_sanction {
-            Some(sanction_account) => {
-                // Deserialize and check if active
-                let sanction_data = sanction_account.try_borrow_data()?;
-                if sanction_data.len() >= 8 + 3 + 1 { // Discriminator + country + is_active
-                    let is_active = sanction_data[8 + 3] == 1;
-                    if is_active && sanction_data.len() >= 8 + 3 + 1 + 1 {
-                        let ubi_pct = sanction_data[8 + 3 + 1];
-                        ubi_pct as u64
-                    } else {
-                        100 // Not active = full UBI
-                    }
-                } else {
-                    100 // Invalid account = full UBI
-                }
-            },
-            None => 100, // No sanction = full 100%
-        };
+        // If it exists, is active and not expired, reduce the claim
+        // proportionally (following its graduated schedule, if any).
+        
+        let sanction_percentage =
+            read_sanction_percentage(&ctx.accounts.country_sanction, current_time)?;
         
         // Apply sanction percentage (max 100, enforced by Governance)
         let actual_claim = initial_claim * sanction_percentage.min(100) / 100;
@@ -357,15 +517,28 @@ pub mod agora_core {
         
         // Mint tokens to user's token account
         let seeds = &[
-            b"protocol",
+            b"protocol".as_ref(),
             &[protocol.bump],
         ];
         let signer_seeds = &[&seeds[..]];
         
+        // Children's tokens go to their Core-owned escrow, not their wallet.
+        // Adults must not create one (it would be an orphaned account).
+        let destination = if user.is_child {
+            let escrow = ctx.accounts.child_escrow.as_ref()
+                .ok_or(CoreError::ChildEscrowRequired)?;
+            user.child_escrow = escrow.key();
+            escrow.to_account_info()
+        } else {
+            require!(ctx.accounts.child_escrow.is_none(), CoreError::ChildEscrowNotAllowed);
+            user.child_escrow = Pubkey::default();
+            ctx.accounts.user_token_account.to_account_info()
+        };
+        
         let cpi_accounts = MintTo {
             mint: ctx.accounts.mint.to_account_info(),
-            to: ctx.accounts.user_token_account.to_account_info(),
-            authority: ctx.accounts.protocol_state.to_account_info(),
+            to: destination,
+            authority: protocol.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
@@ -422,6 +595,7 @@ pub mod agora_core {
     /// - Tokens accumulate at 100 AGORA/day (IMMUTABLE)
     /// - Maximum accumulation: 30 days (3,000 AGORA)
     /// - Tokens older than 30 days expire (rolling window)
+    /// - Partial days carry over to the next claim (no loss to timing)
     /// - Claim frequency: daily, weekly, monthly - user's choice
     /// 
     /// # Liveness Requirement
@@ -429,6 +603,11 @@ pub mod agora_core {
     /// User must have valid liveness verification (renewed annually).
     /// If expired, user must call `verify_liveness` first.
     /// 
+    /// # Attestation Requirement
+    /// 
+    /// The user's SAS attestation is re-validated on every claim: still
+    /// owned by SAS, not revoked, not expired, issuer still trusted.
+    /// 
     /// # Sanction Awareness
     /// 
     /// If user's country is under sanction, they receive reduced UBI:
@@ -436,8 +615,8 @@ pub mod agora_core {
     /// 
     /// # Child Protection
     /// 
-    /// Tokens for users under 18 are minted but LOCKED.
-    /// They cannot be transferred until the user turns 18.
+    /// Tokens for users under 18 are minted into their Core-owned escrow
+    /// and released by `release_child_escrow` once the user turns 18.
     /// 
     /// # Arguments
     /// * `ctx` - Context with user and protocol accounts
@@ -446,128 +625,439 @@ pub mod agora_core {
     /// Emits `DailyClaimed`
     pub fn claim_daily(ctx: Context<ClaimDaily>) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
-        let protocol = &mut ctx.accounts.protocol_state;
+        let accounts = &mut *ctx.accounts;
+        
+        process_claim(
+            ClaimAccounts {
+                user: &mut accounts.user_state,
+                protocol_state: &mut accounts.protocol_state,
+                mint: &accounts.mint,
+                user_token_account: accounts.user_token_account.to_account_info(),
+                child_escrow: accounts.child_escrow.as_ref().map(|e| e.to_account_info()),
+                country_sanction: &accounts.country_sanction,
+                attestation: &accounts.attestation,
+                trusted_issuer: &accounts.trusted_issuer,
+                token_program: &accounts.token_program,
+            },
+            ctx.remaining_accounts,
+            current_time,
+        )?;
+        
+        Ok(())
+    }
+
+    /// Claim daily UBI for several users at once (community kiosks, NGOs).
+    /// 
+    /// Remaining accounts hold one group per user, in order:
+    /// 1. UserState (writable)
+    /// 2. User's wallet (must sign, unless the kiosk is the user's claim delegate)
+    /// 3. Destination: the user's ATA, or child escrow for children (writable)
+    /// 4. Country sanction PDA for the user's citizenship
+    /// 5. User's current SAS attestation
+    /// 6. Trusted issuer of the attestation
+    /// 7. One sanction PDA per additional citizenship
+    /// 
+    /// A user that cannot claim is skipped, not fatal to the batch. At most
+    /// `MAX_BATCH_CLAIMS` users; returns one `BatchClaimResult` per user.
+    /// 
+    /// Claims made as the user's claim delegate are recorded like
+    /// `claim_daily_delegated` (`last_delegated_claim_at`).
+    /// 
+    /// # Events
+    /// Emits `DailyClaimed` per claim (plus `DelegatedClaim` for delegated
+    /// claims), then `BatchClaimed`
+    pub fn batch_claim<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchClaim<'info>>,
+    ) -> Result<Vec<BatchClaimResult>> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let kiosk = ctx.accounts.kiosk.key();
+        let remaining = ctx.remaining_accounts;
+        let mut results = Vec::new();
+        let mut total_amount: u64 = 0;
+        let mut cursor = 0;
+        
+        while cursor < remaining.len() {
+            require!(results.len() < MAX_BATCH_CLAIMS, CoreError::BatchTooLarge);
+            require!(remaining.len() >= cursor + 6, CoreError::InvalidBatchAccounts);
+            
+            let group = &remaining[cursor..];
+            let mut user = Account::<UserState>::try_from(&group[0])?;
+            let extra_count = user.additional_citizenship_count as usize;
+            require!(
+                remaining.len() >= cursor + 6 + extra_count,
+                CoreError::InvalidBatchAccounts
+            );
+            cursor += 6 + extra_count;
+            
+            let owner = user.owner;
+            let delegated = !(group[1].key() == owner && group[1].is_signer);
+            let status = match check_batch_group(
+                &user,
+                group,
+                &kiosk,
+                &ctx.accounts.mint.key(),
+                current_time,
+            ) {
+                Err(status) => status,
+                Ok(()) => {
+                    let claim = process_claim(
+                        ClaimAccounts {
+                            user: &mut user,
+                            protocol_state: &mut ctx.accounts.protocol_state,
+                            mint: &ctx.accounts.mint,
+                            user_token_account: group[2].clone(),
+                            child_escrow: Some(group[2].clone()),
+                            country_sanction: &group[3],
+                            attestation: &group[4],
+                            trusted_issuer: &group[5],
+                            token_program: &ctx.accounts.token_program,
+                        },
+                        &group[6..6 + extra_count],
+                        current_time,
+                    );
+                    match claim {
+                        Ok(amount) => {
+                            if delegated {
+                                user.last_delegated_claim_at = current_time;
+                                emit!(DelegatedClaim {
+                                    user: owner,
+                                    delegate: kiosk,
+                                    amount,
+                                    timestamp: current_time,
+                                });
+                            }
+                            user.exit(&crate::ID)?;
+                            total_amount += amount;
+                            results.push(BatchClaimResult {
+                                user: owner,
+                                amount,
+                                status: BatchClaimStatus::Claimed,
+                            });
+                            continue;
+                        },
+                        Err(_) => BatchClaimStatus::Failed,
+                    }
+                },
+            };
+            
+            results.push(BatchClaimResult { user: owner, amount: 0, status });
+        }
+        
+        emit!(BatchClaimed {
+            kiosk,
+            users: results.len() as u8,
+            claimed: results.iter().filter(|r| r.status == BatchClaimStatus::Claimed).count() as u8,
+            total_amount,
+            timestamp: current_time,
+        });
+        
+        Ok(results)
+    }
+
+    /// Preview what `claim_daily` would pay right now (read-only).
+    /// 
+    /// Intended for `simulateTransaction`: wallets get the result as return
+    /// data without reimplementing sanction parsing or the 30-day window.
+    /// Sanction PDAs for additional citizenships follow as remaining accounts.
+    pub fn preview_claim(ctx: Context<PreviewClaim>) -> Result<ClaimPreview> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &ctx.accounts.user_state;
+        
+        let (claimable_days, forfeited_days) = accrued_days(user, current_time);
+        let base_amount = claimable_days * DAILY_AMOUNT;
+        let sanction_percentage = effective_sanction_percentage(
+            user,
+            &ctx.accounts.country_sanction,
+            ctx.remaining_accounts,
+            current_time,
+        )?;
+        
+        Ok(ClaimPreview {
+            claimable_days,
+            base_amount,
+            sanction_percentage,
+            actual_amount: base_amount * sanction_percentage.min(100) / 100,
+            liveness_deadline: user.liveness_expires_at + LIVENESS_GRACE_PERIOD,
+            forfeited_days,
+            is_locked: user.is_child
+                && current_age_days(user, current_time) < CHILD_AGE_THRESHOLD,
+            can_claim: user.is_verified
+                && claimable_days > 0
+                && current_time <= user.liveness_expires_at + LIVENESS_GRACE_PERIOD,
+        })
+    }
+
+    /// Set (or clear, with the default key) the wallet allowed to claim
+    /// on the user's behalf.
+    /// 
+    /// # Events
+    /// Emits `ClaimDelegateSet`
+    pub fn set_claim_delegate(ctx: Context<SetClaimDelegate>, delegate: Pubkey) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
         let user = &mut ctx.accounts.user_state;
         
-        // ====================================================================
-        // VERIFICATION CHECKS
-        // ====================================================================
+        require_keys_neq!(delegate, user.owner, CoreError::InvalidClaimDelegate);
+        user.claim_delegate = delegate;
         
-        require!(user.is_verified, CoreError::UserNotVerified);
+        emit!(ClaimDelegateSet {
+            user: user.owner,
+            delegate,
+            timestamp: current_time,
+        });
         
-        // Check liveness (with grace period)
-        let liveness_deadline = user.liveness_expires_at + LIVENESS_GRACE_PERIOD;
-        require!(
-            current_time <= liveness_deadline,
-            CoreError::LivenessExpired
+        Ok(())
+    }
+
+    /// Claim daily UBI on a user's behalf (user-authorised delegate only).
+    /// 
+    /// Same rules as `claim_daily`: tokens always go to the user's own
+    /// token account (or child escrow), never to the delegate. Lets users
+    /// without daily phone access avoid losing days beyond the 30-day window.
+    /// 
+    /// Governance may reimburse the delegate's fees from the gas pool; it
+    /// reads `last_delegated_claim_at` to pay once per delegated claim.
+    /// 
+    /// # Events
+    /// Emits `DailyClaimed`, `DelegatedClaim`
+    pub fn claim_daily_delegated(ctx: Context<ClaimDailyDelegated>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let accounts = &mut *ctx.accounts;
+        
+        let amount = process_claim(
+            ClaimAccounts {
+                user: &mut accounts.user_state,
+                protocol_state: &mut accounts.protocol_state,
+                mint: &accounts.mint,
+                user_token_account: accounts.user_token_account.to_account_info(),
+                child_escrow: accounts.child_escrow.as_ref().map(|e| e.to_account_info()),
+                country_sanction: &accounts.country_sanction,
+                attestation: &accounts.attestation,
+                trusted_issuer: &accounts.trusted_issuer,
+                token_program: &accounts.token_program,
+            },
+            ctx.remaining_accounts,
+            current_time,
+        )?;
+        
+        let user = &mut ctx.accounts.user_state;
+        user.last_delegated_claim_at = current_time;
+        
+        emit!(DelegatedClaim {
+            user: user.owner,
+            delegate: ctx.accounts.delegate.key(),
+            amount,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    // ========================================================================
+    // CHILD ESCROW
+    // ========================================================================
+
+    /// Mark a child as adult once they turn 18 (anyone can call).
+    /// 
+    /// Age is recomputed from `age_in_days_at_registration` and
+    /// `registration_timestamp`, so this works even when the user cannot
+    /// claim (lapsed liveness, nothing to claim).
+    /// 
+    /// # Events
+    /// Emits `UserCameOfAge`
+    pub fn unlock_adult(ctx: Context<UnlockAdult>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
+        
+        require!(user.is_child, CoreError::NotAChild);
+        require!(unlock_if_adult(user, current_time), CoreError::NotYetAdult);
+        
+        emit!(UserCameOfAge {
+            user: user.owner,
+            child_escrow: user.child_escrow,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Link a parent/guardian to a child, proved by a guardianship attestation.
+    /// 
+    /// The attestation must be issued to the guardian's wallet by a trusted
+    /// issuer, use the guardianship schema and name the child's wallet.
+    /// Replacing an existing guardian also requires the current guardian's
+    /// signature.
+    /// 
+    /// # Events
+    /// Emits `ChildGuardianLinked`
+    pub fn link_child_guardian(ctx: Context<LinkChildGuardian>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let guardian = ctx.accounts.guardian.key();
+        let child = &mut ctx.accounts.child_state;
+        
+        require!(child.is_child, CoreError::NotAChild);
+        
+        if child.guardian != Pubkey::default() && child.guardian != guardian {
+            let current = ctx.accounts.current_guardian.as_ref()
+                .ok_or(CoreError::GuardianConsentRequired)?;
+            require_keys_eq!(current.key(), child.guardian, CoreError::GuardianConsentRequired);
+        }
+        
+        validate_attestation(
+            &ctx.accounts.attestation,
+            &ctx.accounts.trusted_issuer,
+            &guardian,
+            current_time,
+        )?;
+        require_keys_eq!(
+            read_guardianship_ward(&ctx.accounts.attestation)?,
+            child.owner,
+            CoreError::InvalidGuardianship
         );
         
-        // ====================================================================
-        // CALCULATE CLAIMABLE AMOUNT
-        // ====================================================================
+        child.guardian = guardian;
+        child.allowance_period_start = 0;
+        child.allowance_withdrawn = 0;
+        
+        emit!(ChildGuardianLinked {
+            child: child.owner,
+            guardian,
+            attestation: ctx.accounts.attestation.key(),
+            timestamp: current_time,
+        });
         
-        let seconds_since_last_claim = current_time - user.last_claim_timestamp;
-        let days_since_last_claim = (seconds_since_last_claim / SECONDS_PER_DAY) as u64;
+        Ok(())
+    }
+    
+    /// Withdraw a capped allowance from a child's escrow (guardian only).
+    /// 
+    /// At most `child_allowance_monthly_cap` (Governance CoreParameters)
+    /// per `CHILD_ALLOWANCE_PERIOD`. Funds go to the guardian's token
+    /// account, for the child's benefit. Age is recomputed, so the guardian
+    /// loses access on the child's 18th birthday even before `unlock_adult`.
+    /// 
+    /// # Events
+    /// Emits `ChildAllowanceWithdrawn`
+    pub fn withdraw_child_allowance(
+        ctx: Context<WithdrawChildAllowance>,
+        amount: u64,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let child = &mut ctx.accounts.child_state;
         
-        // Cap at maximum accumulation window
-        let claimable_days = days_since_last_claim.min(MAX_ACCUMULATION_DAYS);
+        require!(
+            child.is_child && current_age_days(child, current_time) < CHILD_AGE_THRESHOLD,
+            CoreError::NotAChild
+        );
+        require!(amount > 0, CoreError::InvalidAmount);
         
-        require!(claimable_days > 0, CoreError::NothingToClaim);
+        let monthly_cap = read_child_allowance_cap(&ctx.accounts.core_parameters)?;
         
-        // Base amount (before sanctions)
-        let base_amount = claimable_days * DAILY_AMOUNT;
+        if current_time >= child.allowance_period_start + CHILD_ALLOWANCE_PERIOD {
+            child.allowance_period_start = current_time;
+            child.allowance_withdrawn = 0;
+        }
+        let withdrawn = child.allowance_withdrawn
+            .checked_add(amount)
+            .ok_or(CoreError::AllowanceCapExceeded)?;
+        require!(withdrawn <= monthly_cap, CoreError::AllowanceCapExceeded);
         
-        // ====================================================================
-        // CHECK FOR COUNTRY SANCTION
-        // ====================================================================
+        let bump = [ctx.accounts.protocol_state.bump];
+        let seeds: &[&[u8]] = &[b"protocol", &bump];
+        let signer_seeds = &[seeds];
         
-        let sanction_percentage = match &ctx.accounts.country_sanction {
-            Some(sanction_account) => {
-                let sanction_data = sanction_account.try_borrow_data()?;
-                if sanction_data.len() >= 8 + 3 + 1 + 1 {
-                    let is_active = sanction_data[8 + 3] == 1;
-                    if is_active {
-                        let ubi_pct = sanction_data[8 + 3 + 1];
-                        ubi_pct as u64
-                    } else {
-                        100
-                    }
-                } else {
-                    100
-                }
-            },
-            None => 100,
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.child_escrow.to_account_info(),
+            to: ctx.accounts.guardian_token_account.to_account_info(),
+            authority: ctx.accounts.protocol_state.to_account_info(),
         };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount)?;
         
-        // ====================================================================
-        // APPLY SANCTION AND MINT
-        // ====================================================================
-        // This is the critical formula - sanctions can only REDUCE, never INCREASE
+        child.allowance_withdrawn = withdrawn;
+        child.locked_balance = child.locked_balance.saturating_sub(amount);
+        
+        emit!(ChildAllowanceWithdrawn {
+            child: child.owner,
+            guardian: child.guardian,
+            amount,
+            withdrawn_this_period: withdrawn,
+            monthly_cap,
+            timestamp: current_time,
+        });
         
-        let actual_amount = base_amount * sanction_percentage.min(100) / 100;
+        msg!("Child allowance withdrawn: {} AGORA", amount / 1_000_000_000);
         
-        // Mint tokens
-        let seeds = &[
-            b"protocol",
-            &[protocol.bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
+        Ok(())
+    }
+    
+    /// Release a child's escrowed tokens once they turn 18.
+    /// 
+    /// Anyone can call this. Moves the whole escrow balance to the user's
+    /// token account and closes the escrow (rent returns to the user).
+    /// 
+    /// # Events
+    /// Emits `ChildEscrowReleased` (and `UserCameOfAge` if not yet unlocked)
+    pub fn release_child_escrow(ctx: Context<ReleaseChildEscrow>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
         
-        let cpi_accounts = MintTo {
-            mint: ctx.accounts.mint.to_account_info(),
+        require!(
+            current_age_days(user, current_time) >= CHILD_AGE_THRESHOLD,
+            CoreError::NotYetAdult
+        );
+        let came_of_age = unlock_if_adult(user, current_time);
+        
+        let amount = ctx.accounts.child_escrow.amount;
+        
+        let bump = [ctx.accounts.protocol_state.bump];
+        let seeds: &[&[u8]] = &[b"protocol", &bump];
+        let signer_seeds = &[seeds];
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.child_escrow.to_account_info(),
             to: ctx.accounts.user_token_account.to_account_info(),
             authority: ctx.accounts.protocol_state.to_account_info(),
         };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        
-        token::mint_to(cpi_ctx, actual_amount)?;
-        
-        // ====================================================================
-        // UPDATE STATE
-        // ====================================================================
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount)?;
         
-        user.last_claim_timestamp = current_time;
-        user.total_claimed += actual_amount as u128;
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.child_escrow.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.protocol_state.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::close_account(cpi_ctx)?;
         
-        // If child, add to locked balance
-        if user.is_child {
-            // Check if user has turned 18 since registration
-            let current_age_days = user.age_in_days_at_registration + 
-                ((current_time - user.registration_timestamp) / SECONDS_PER_DAY) as u64;
-            
-            if current_age_days >= CHILD_AGE_THRESHOLD {
-                // User turned 18 - unlock everything
-                user.is_child = false;
-                user.locked_balance = 0;
-            } else {
-                // Still a child - lock the new tokens
-                user.locked_balance += actual_amount;
-            }
+        if came_of_age {
+            emit!(UserCameOfAge {
+                user: user.owner,
+                child_escrow: user.child_escrow,
+                timestamp: current_time,
+            });
         }
         
-        protocol.total_minted += actual_amount as u128;
-        
-        // ====================================================================
-        // EMIT EVENT
-        // ====================================================================
+        user.child_escrow = Pubkey::default();
         
-        emit!(DailyClaimed {
+        emit!(ChildEscrowReleased {
             user: user.owner,
-            days_claimed: claimable_days,
-            base_amount,
-            sanction_percentage,
-            actual_amount,
-            is_locked: user.is_child,
+            amount,
             timestamp: current_time,
         });
         
-        msg!(
-            "Claimed {} days = {} AGORA ({}% UBI)",
-            claimable_days,
-            actual_amount / 1_000_000_000,
-            sanction_percentage
-        );
+        msg!("Child escrow released: {} AGORA", amount / 1_000_000_000);
         
         Ok(())
     }
@@ -593,7 +1083,8 @@ pub mod agora_core {
     /// 
     /// - Liveness valid for 365 days
     /// - 30-day grace period after expiry
-    /// - Warning notifications at 60 days before expiry
+    /// - Warning at 60 days before expiry: `LivenessWarning` is emitted on
+    ///   the first claim in that window; `get_liveness_status` can be queried
     /// 
     /// # Arguments
     /// * `ctx` - Context with user account
@@ -628,6 +1119,9 @@ pub mod agora_core {
         user.liveness_verified_at = current_time;
         user.liveness_expires_at = current_time + LIVENESS_PERIOD_SECONDS;
         
+        // Coming back before closure ends dormancy
+        user.dormant_since = 0;
+        
         emit!(LivenessVerified {
             user: user.owner,
             verified_at: current_time,
@@ -639,6 +1133,14 @@ pub mod agora_core {
         Ok(())
     }
 
+    /// Return the user's liveness status (read-only, via return data).
+    /// 
+    /// Valid → Warning (last 60 days) → Grace (30 days after expiry) → Expired
+    pub fn get_liveness_status(ctx: Context<GetLivenessStatus>) -> Result<LivenessStatus> {
+        let current_time = Clock::get()?.unix_timestamp;
+        Ok(liveness_status(&ctx.accounts.user_state, current_time))
+    }
+
     // ========================================================================
     // BIOMETRIC UPDATE
     // ========================================================================
@@ -738,6 +1240,7 @@ pub mod agora_core {
         new_bio_record.registered_at = current_time;
         new_bio_record.is_registered = true;
         new_bio_record.previous_hash = Some(old_bio_record.hash);
+        new_bio_record.is_retired = false;
         new_bio_record.bump = ctx.bumps.new_biometric_record;
         
         // Registry count stays the same (1 invalidated, 1 added)
@@ -968,431 +1471,3444 @@ pub mod agora_core {
         // ====================================================================
         // VALIDATE NEW ATTESTATION
         // ====================================================================
-        // Read and verify the SAS attestation account
-        
-        let attestation_data = ctx.accounts.new_attestation.try_borrow_data()?;
-        
-        // SAS Attestation structure (from SAS program):
-        // - 8 bytes: discriminator
-        // - 32 bytes: credential (issuer)
-        // - 32 bytes: schema
-        // - 32 bytes: nonce (usually wallet pubkey)
-        // - 8 bytes: expiry timestamp
-        // - 1 byte: is_revoked
-        // - variable: data
-        
-        require!(
-            attestation_data.len() >= 8 + 32 + 32 + 32 + 8 + 1,
-            CoreError::InvalidAttestation
-        );
+        // Must be a valid SAS attestation from an active trusted issuer
+        // (read from Governance), linked to this wallet, not expired and
+        // not revoked.
         
-        // Extract credential (issuer)
-        let credential_bytes: [u8; 32] = attestation_data[8..40].try_into().unwrap();
-        let credential = Pubkey::new_from_array(credential_bytes);
+        validate_attestation(
+            &ctx.accounts.new_attestation,
+            &ctx.accounts.trusted_issuer,
+            &ctx.accounts.owner.key(),
+            current_time,
+        )?;
         
-        // Extract nonce (should be user's wallet)
-        let nonce_bytes: [u8; 32] = attestation_data[72..104].try_into().unwrap();
-        let nonce = Pubkey::new_from_array(nonce_bytes);
+        // ====================================================================
+        // UPDATE USER STATE
+        // ====================================================================
         
-        // Extract expiry
-        let expiry_bytes: [u8; 8] = attestation_data[104..112].try_into().unwrap();
-        let expiry = i64::from_le_bytes(expiry_bytes);
+        let old_attestation = user.attestation;
         
-        // Extract revocation status
-        let is_revoked = attestation_data[112] == 1;
+        user.attestation = ctx.accounts.new_attestation.key();
+        user.attestation_updated_at = current_time;
+        user.attestation_update_count = user.attestation_update_count.saturating_add(1);
+        user.attestation_lapsed_at = 0;
+        user.attestation_issuer = read_attestation_credential(&ctx.accounts.new_attestation);
+        user.last_transaction_timestamp = current_time;
         
         // ====================================================================
-        // ATTESTATION VALIDITY CHECKS
+        // EMIT EVENT
         // ====================================================================
         
-        // Check 1: Attestation must be from trusted issuer (read from Governance)
-        // Governance manages the list of trusted issuers via Constitutional proposals
-        let issuer_data = ctx.accounts.trusted_issuer.try_borrow_data()?;
+        emit!(AttestationUpdated {
+            user: user.owner,
+            old_attestation,
+            new_attestation: user.attestation,
+            update_count: user.attestation_update_count,
+            timestamp: current_time,
+        });
         
-        // TrustedIssuer structure from Governance:
-        // - 8 bytes: discriminator
-        // - 32 bytes: credential
-        // - 32 bytes: name
-        // - 8 bytes: approved_at
-        // - 8 bytes: proposal_id
-        // - 1 byte: is_active
-        require!(
-            issuer_data.len() >= 8 + 32 + 32 + 8 + 8 + 1,
-            CoreError::InvalidAttestation
+        msg!(
+            "Attestation updated for user. Update count: {}",
+            user.attestation_update_count
         );
         
-        // Extract issuer credential and is_active
-        let issuer_credential_bytes: [u8; 32] = issuer_data[8..40].try_into().unwrap();
-        let issuer_credential = Pubkey::new_from_array(issuer_credential_bytes);
-        let issuer_is_active = issuer_data[88] == 1; // offset: 8 + 32 + 32 + 8 + 8
-        
-        // Verify attestation credential matches trusted issuer
-        require!(
-            credential == issuer_credential,
-            CoreError::UntrustedIssuer
-        );
+        Ok(())
+    }
+
+    /// Flag a user whose SAS attestation has lapsed (permissionless crank).
+    /// 
+    /// An attestation lapses when it is closed, revoked, expired, or its
+    /// issuer is no longer trusted (after the issuer's migration deadline).
+    /// Claims already fail in that case; the flag makes affected users
+    /// visible (indexers, wallets) until they call `update_attestation`.
+    /// 
+    /// The issuer passed must be the attestation's own issuer, so a valid
+    /// attestation cannot be flagged by supplying the wrong issuer.
+    /// 
+    /// # Events
+    /// Emits `AttestationLapsed`
+    pub fn flag_lapsed_attestation(ctx: Context<FlagLapsedAttestation>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
+        
+        require!(user.attestation_lapsed_at == 0, CoreError::AttestationAlreadyFlagged);
+        
+        let attestation = &ctx.accounts.attestation;
+        if *attestation.owner == SAS_PROGRAM_ID {
+            let data = attestation.try_borrow_data()?;
+            if data.len() >= SAS_CREDENTIAL_OFFSET + 32 {
+                let credential = Pubkey::new_from_array(
+                    data[SAS_CREDENTIAL_OFFSET..SAS_CREDENTIAL_OFFSET + 32].try_into().unwrap()
+                );
+                require_keys_eq!(
+                    credential,
+                    ctx.accounts.trusted_issuer_credential.key(),
+                    CoreError::InvalidAttestation
+                );
+            }
+        }
+        
+        let lapsed = validate_existing_attestation(
+            attestation,
+            &ctx.accounts.trusted_issuer,
+            &user.owner,
+            current_time,
+        ).is_err();
+        require!(lapsed, CoreError::AttestationStillValid);
+        
+        user.attestation_lapsed_at = current_time;
+        
+        emit!(AttestationLapsed {
+            user: user.owner,
+            attestation: user.attestation,
+            timestamp: current_time,
+        });
+        
+        msg!("Attestation lapsed for user {}", user.owner);
+        
+        Ok(())
+    }
+
+    // ========================================================================
+    // ADDITIONAL CITIZENSHIPS (⚫ IMMUTABLE - dual citizenship support)
+    // ========================================================================
+
+    /// Add another attested citizenship to the user's account.
+    /// 
+    /// Dual (or triple) citizens register with one citizenship and add the
+    /// others here, each proven by a citizenship-schema SAS attestation from
+    /// a trusted issuer whose data carries the ISO 3166-1 alpha-3 country
+    /// code. Shares `CITIZENSHIP_UPDATE_COOLDOWN` with `update_citizenship`.
+    /// 
+    /// # Sanctions
+    /// 
+    /// When claiming, the sanction accounts for ALL citizenships are
+    /// checked and the most favourable percentage applies. A citizenship
+    /// therefore never makes a user worse off than their best one, and
+    /// cannot be hidden to dodge a sanction on another.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Context with user and attestation accounts
+    /// 
+    /// # Events
+    /// Emits `CitizenshipAdded`
+    pub fn add_citizenship(ctx: Context<AddCitizenship>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
+        
+        // ====================================================================
+        // RATE LIMITING
+        // ====================================================================
         
-        // Verify issuer is still active
         require!(
-            issuer_is_active,
-            CoreError::UntrustedIssuer
+            current_time - user.citizenship_updated_at >= CITIZENSHIP_UPDATE_COOLDOWN,
+            CoreError::CitizenshipUpdateTooSoon
         );
         
-        // Check 2: Attestation must be linked to this wallet
+        // ====================================================================
+        // VALIDATE ATTESTATION
+        // ====================================================================
+        
+        validate_attestation(
+            &ctx.accounts.attestation,
+            &ctx.accounts.trusted_issuer,
+            &ctx.accounts.owner.key(),
+            current_time,
+        )?;
+        
+        let country = read_attested_country(&ctx.accounts.attestation)?;
+        
+        // ====================================================================
+        // ADD CITIZENSHIP
+        // ====================================================================
+        
+        let count = user.additional_citizenship_count as usize;
         require!(
-            nonce == ctx.accounts.owner.key(),
-            CoreError::AttestationWalletMismatch
+            count < MAX_ADDITIONAL_CITIZENSHIPS,
+            CoreError::TooManyCitizenships
         );
-        
-        // Check 3: Attestation must not be expired
         require!(
-            expiry == 0 || expiry > current_time,
-            CoreError::AttestationExpired
+            country != user.citizenship
+                && !user.additional_citizenships[..count].contains(&country),
+            CoreError::CitizenshipAlreadyHeld
         );
         
-        // Check 4: Attestation must not be revoked
+        user.additional_citizenships[count] = country;
+        user.additional_citizenship_count += 1;
+        user.citizenship_updated_at = current_time;
+        user.last_transaction_timestamp = current_time;
+        
+        emit!(CitizenshipAdded {
+            user: user.owner,
+            country,
+            attestation: ctx.accounts.attestation.key(),
+            citizenship_count: user.additional_citizenship_count + 1,
+            timestamp: current_time,
+        });
+        
+        msg!("Citizenship {:?} added", country);
+        
+        Ok(())
+    }
+
+    /// Change the user's primary citizenship (e.g. after naturalisation).
+    /// 
+    /// Requires a citizenship-schema SAS attestation from a trusted issuer
+    /// carrying the new country code. Limited to once per
+    /// `CITIZENSHIP_UPDATE_COOLDOWN` (counted from registration or the
+    /// previous change or addition) so users cannot hop between countries
+    /// to dodge sanctions.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Context with user and attestation accounts
+    /// 
+    /// # Events
+    /// Emits `CitizenshipUpdated`
+    pub fn update_citizenship(ctx: Context<UpdateCitizenship>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
+        
+        // ====================================================================
+        // RATE LIMITING
+        // ====================================================================
+        
         require!(
-            !is_revoked,
-            CoreError::AttestationRevoked
+            current_time - user.citizenship_updated_at >= CITIZENSHIP_UPDATE_COOLDOWN,
+            CoreError::CitizenshipUpdateTooSoon
         );
         
         // ====================================================================
-        // UPDATE USER STATE
+        // VALIDATE ATTESTATION
         // ====================================================================
         
-        let old_attestation = user.attestation;
+        validate_attestation(
+            &ctx.accounts.attestation,
+            &ctx.accounts.trusted_issuer,
+            &ctx.accounts.owner.key(),
+            current_time,
+        )?;
         
-        user.attestation = ctx.accounts.new_attestation.key();
-        user.attestation_updated_at = current_time;
-        user.attestation_update_count = user.attestation_update_count.saturating_add(1);
-        user.last_transaction_timestamp = current_time;
+        let new_citizenship = read_attested_country(&ctx.accounts.attestation)?;
+        require!(
+            new_citizenship != user.citizenship,
+            CoreError::CitizenshipAlreadyHeld
+        );
         
         // ====================================================================
-        // EMIT EVENT
+        // UPDATE CITIZENSHIP
         // ====================================================================
         
-        emit!(AttestationUpdated {
+        // If the new country was an additional citizenship, it moves to
+        // primary and is removed from the additional list
+        let count = user.additional_citizenship_count as usize;
+        if let Some(index) = user.additional_citizenships[..count]
+            .iter()
+            .position(|country| *country == new_citizenship)
+        {
+            user.additional_citizenships[index] = user.additional_citizenships[count - 1];
+            user.additional_citizenships[count - 1] = [0u8; 3];
+            user.additional_citizenship_count -= 1;
+        }
+        
+        let old_citizenship = user.citizenship;
+        
+        user.citizenship = new_citizenship;
+        user.citizenship_updated_at = current_time;
+        user.citizenship_update_count = user.citizenship_update_count.saturating_add(1);
+        user.last_transaction_timestamp = current_time;
+        
+        emit!(CitizenshipUpdated {
             user: user.owner,
-            old_attestation,
-            new_attestation: user.attestation,
-            update_count: user.attestation_update_count,
+            old_citizenship,
+            new_citizenship,
+            attestation: ctx.accounts.attestation.key(),
+            update_count: user.citizenship_update_count,
             timestamp: current_time,
         });
         
         msg!(
-            "Attestation updated for user. Update count: {}",
-            user.attestation_update_count
+            "Citizenship updated from {:?} to {:?}. Update count: {}",
+            old_citizenship,
+            new_citizenship,
+            user.citizenship_update_count
         );
         
         Ok(())
     }
-}
 
-// ╔═══════════════════════════════════════════════════════════════════════════╗
-// ║                                                                           ║
-// ║                           ACCOUNT STRUCTURES                              ║
-// ║                                                                           ║
-// ╚═══════════════════════════════════════════════════════════════════════════╝
+    // ========================================================================
+    // WALLET ROTATION (⚫ IMMUTABLE - key loss / compromise recovery)
+    // ========================================================================
 
-// ============================================================================
-// PROTOCOL STATE
-// ============================================================================
+    /// Request moving the user's account to a new wallet.
+    /// 
+    /// Signed by the NEW wallet. The person proves they are the registered
+    /// human with a live biometric scan and a fresh identity-schema SAS
+    /// attestation tied to the new wallet. The biometric hash is public (it
+    /// seeds the BiometricRecord PDA), so the binding comes from the
+    /// attestation: a trusted issuer must attest that the new wallet belongs
+    /// to the human with that hash. The rotation can be completed after
+    /// `WALLET_ROTATION_COOLDOWN`; until then the old wallet can cancel it.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Context with user, biometric and attestation accounts
+    /// * `live_biometric_hash` - Hash of LIVE biometric scan
+    /// 
+    /// # Events
+    /// Emits `WalletRotationRequested`
+    pub fn request_wallet_rotation(
+        ctx: Context<RequestWalletRotation>,
+        live_biometric_hash: [u8; 32],
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &ctx.accounts.user_state;
+        let new_owner = ctx.accounts.new_owner.key();
+        
+        // ====================================================================
+        // VERIFY IDENTITY
+        // ====================================================================
+        
+        require!(user.is_verified, CoreError::UserNotVerified);
+        require!(new_owner != user.owner, CoreError::InvalidRotationTarget);
+        require!(
+            ctx.accounts.new_user_state.data_is_empty(),
+            CoreError::InvalidRotationTarget
+        );
+        
+        require!(
+            live_biometric_hash == ctx.accounts.biometric_record.hash,
+            CoreError::BiometricMismatch
+        );
+        
+        validate_attestation(
+            &ctx.accounts.attestation,
+            &ctx.accounts.trusted_issuer,
+            &new_owner,
+            current_time,
+        )?;
+        require!(
+            read_attested_biometric(&ctx.accounts.attestation)? == ctx.accounts.biometric_record.hash,
+            CoreError::BiometricMismatch
+        );
+        
+        // ====================================================================
+        // RECORD PENDING ROTATION
+        // ====================================================================
+        
+        let rotation = &mut ctx.accounts.wallet_rotation;
+        rotation.user_state = user.key();
+        rotation.old_owner = user.owner;
+        rotation.new_owner = new_owner;
+        rotation.new_attestation = ctx.accounts.attestation.key();
+        rotation.new_attestation_issuer = ctx.accounts.trusted_issuer_credential.key();
+        rotation.requested_at = current_time;
+        rotation.executable_at = current_time + WALLET_ROTATION_COOLDOWN;
+        rotation.bump = ctx.bumps.wallet_rotation;
+        
+        emit!(WalletRotationRequested {
+            old_owner: rotation.old_owner,
+            new_owner,
+            executable_at: rotation.executable_at,
+            timestamp: current_time,
+        });
+        
+        msg!("Wallet rotation requested. Executable after {}", rotation.executable_at);
+        
+        Ok(())
+    }
 
-/// Global protocol state - singleton PDA.
-/// 
-/// Stores protocol-wide statistics and references.
-/// Created once during initialization.
-#[account]
-pub struct ProtocolState {
-    /// Original deployer authority (for reference only, no special powers)
-    pub authority: Pubkey,
-    
-    /// AGORA token mint address
-    pub mint: Pubkey,
-    
-    /// Protocol treasury address
-    pub treasury: Pubkey,
-    
-    /// Total registered users
-    pub total_users: u64,
-    
-    /// Total tokens ever minted (including burned)
-    pub total_minted: u128,
-    
-    /// Total tokens burned
-    pub total_burned: u128,
-    
-    /// Timestamp when protocol was launched
-    pub launch_timestamp: i64,
-    
-    /// Whether protocol is initialized
-    pub is_initialized: bool,
-    
-    /// PDA bump seed
-    pub bump: u8,
-}
+    /// Cancel a pending wallet rotation.
+    /// 
+    /// Signed by the CURRENT wallet during the cooldown. Protects users
+    /// whose identity data was used by someone else to request a rotation.
+    /// 
+    /// # Events
+    /// Emits `WalletRotationCancelled`
+    pub fn cancel_wallet_rotation(ctx: Context<CancelWalletRotation>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let rotation = &ctx.accounts.wallet_rotation;
+        
+        emit!(WalletRotationCancelled {
+            old_owner: rotation.old_owner,
+            new_owner: rotation.new_owner,
+            timestamp: current_time,
+        });
+        
+        msg!("Wallet rotation cancelled by current owner");
+        
+        Ok(())
+    }
 
-impl ProtocolState {
-    pub const SIZE: usize = 8 +  // Discriminator
-        32 +    // authority
-        32 +    // mint
-        32 +    // treasury
-        8 +     // total_users
-        16 +    // total_minted
-        16 +    // total_burned
-        8 +     // launch_timestamp
-        1 +     // is_initialized
-        1;      // bump
-}
+    /// Complete a wallet rotation after the cooldown.
+    /// 
+    /// Signed by the NEW wallet. Re-checks the identity attestation, moves
+    /// the UserState (claim history, liveness, citizenship, locked balance)
+    /// to the PDA of the new wallet, re-links the biometric record and
+    /// closes the old UserState and its recovery config (guardians must be
+    /// nominated again by the new wallet).
+    /// 
+    /// Tokens already held by the old wallet are not moved - they belong
+    /// to whoever controls that key.
+    /// 
+    /// # Events
+    /// Emits `WalletRotated`
+    pub fn complete_wallet_rotation(ctx: Context<CompleteWalletRotation>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let rotation = &ctx.accounts.wallet_rotation;
+        
+        require!(
+            current_time >= rotation.executable_at,
+            CoreError::RotationCooldownActive
+        );
+        
+        // The attestation may have been revoked during the cooldown
+        validate_attestation(
+            &ctx.accounts.attestation,
+            &ctx.accounts.trusted_issuer,
+            &rotation.new_owner,
+            current_time,
+        )?;
+        require!(
+            read_attested_biometric(&ctx.accounts.attestation)? == ctx.accounts.biometric_record.hash,
+            CoreError::BiometricMismatch
+        );
+        
+        close_if_exists(
+            &ctx.accounts.recovery_config,
+            &ctx.accounts.new_owner.to_account_info(),
+        )?;
+        
+        let migrated = migrate_user_state(
+            &ctx.accounts.old_user_state,
+            ctx.accounts.old_user_state.key(),
+            rotation.new_owner,
+            rotation.new_attestation,
+            rotation.new_attestation_issuer,
+            ctx.bumps.new_user_state,
+            current_time,
+        );
+        ctx.accounts.new_user_state.set_inner(migrated);
+        
+        ctx.accounts.biometric_record.user = rotation.new_owner;
+        
+        emit!(WalletRotated {
+            old_owner: rotation.old_owner,
+            new_owner: rotation.new_owner,
+            new_attestation: rotation.new_attestation,
+            timestamp: current_time,
+        });
+        
+        msg!("Wallet rotated to {}", rotation.new_owner);
+        
+        Ok(())
+    }
 
-// ============================================================================
-// USER STATE
-// ============================================================================
+    // ========================================================================
+    // SOCIAL RECOVERY (⚫ IMMUTABLE - guardian-approved wallet recovery)
+    // ========================================================================
 
-/// Individual user account state.
-/// 
-/// Created when a user registers. Tracks their claims, transactions,
-/// and verification status.
-#[account]
-pub struct UserState {
-    /// User's wallet address
-    pub owner: Pubkey,
-    
-    /// When user registered
-    pub registration_timestamp: i64,
-    
-    /// Last time user claimed UBI
-    pub last_claim_timestamp: i64,
-    
-    /// User's age in days at registration
-    pub age_in_days_at_registration: u64,
-    
-    /// User's citizenship (ISO 3166-1 alpha-3)
-    pub citizenship: [u8; 3],
-    
-    /// Whether user passed identity verification
-    pub is_verified: bool,
-    
-    /// Whether user is under 18
-    pub is_child: bool,
-    
-    /// Total AGORA ever claimed (for statistics)
-    pub total_claimed: u128,
-    
-    /// Locked balance (for children)
+    /// Nominate recovery guardians for the user's account.
+    /// 
+    /// Guardians are 3-5 other verified humans. Their UserState PDAs must be
+    /// passed as remaining accounts, in the same order as `guardians`.
+    /// A majority `threshold` of them can jointly move the account to a new
+    /// wallet after a challenge period the current wallet can veto.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Context with user and recovery accounts
+    /// * `guardians` - Guardian wallets
+    /// * `threshold` - Approvals required (more than half the guardians)
+    /// 
+    /// # Events
+    /// Emits `RecoveryGuardiansSet`
+    pub fn configure_recovery(
+        ctx: Context<ConfigureRecovery>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let config = &mut ctx.accounts.recovery_config;
+        
+        config.user_state = ctx.accounts.user_state.key();
+        config.bump = ctx.bumps.recovery_config;
+        set_recovery_guardians(
+            config,
+            &ctx.accounts.owner.key(),
+            &guardians,
+            threshold,
+            ctx.remaining_accounts,
+        )?;
+        
+        emit!(RecoveryGuardiansSet {
+            user: ctx.accounts.owner.key(),
+            guardians,
+            threshold,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Replace the recovery guardians (not allowed during a recovery).
+    /// 
+    /// Same rules as `configure_recovery`.
+    /// 
+    /// # Events
+    /// Emits `RecoveryGuardiansSet`
+    pub fn update_recovery_guardians(
+        ctx: Context<UpdateRecoveryGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let config = &mut ctx.accounts.recovery_config;
+        
+        require!(
+            config.pending_new_owner == Pubkey::default(),
+            CoreError::RecoveryInProgress
+        );
+        
+        set_recovery_guardians(
+            config,
+            &ctx.accounts.owner.key(),
+            &guardians,
+            threshold,
+            ctx.remaining_accounts,
+        )?;
+        
+        emit!(RecoveryGuardiansSet {
+            user: ctx.accounts.owner.key(),
+            guardians,
+            threshold,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Start a recovery to `new_owner` (signed by a guardian).
+    /// 
+    /// Counts as the proposing guardian's approval. Replaces a pending
+    /// recovery once it has stalled for `RECOVERY_PROPOSAL_EXPIRY`.
+    /// 
+    /// # Events
+    /// Emits `RecoveryProposed`
+    pub fn propose_recovery(ctx: Context<ProposeRecovery>, new_owner: Pubkey) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let config = &mut ctx.accounts.recovery_config;
+        let guardian = ctx.accounts.guardian.key();
+        
+        let index = config.guardian_index(&guardian).ok_or(CoreError::NotRecoveryGuardian)?;
+        require!(
+            config.pending_new_owner == Pubkey::default() || config.is_stale(current_time),
+            CoreError::RecoveryInProgress
+        );
+        require!(
+            new_owner != Pubkey::default()
+                && new_owner != ctx.accounts.user_state.owner
+                && ctx.accounts.new_user_state.data_is_empty(),
+            CoreError::InvalidRotationTarget
+        );
+        
+        config.pending_new_owner = new_owner;
+        config.approvals = 1 << index;
+        config.proposed_at = current_time;
+        config.challenge_ends_at = 0;
+        
+        emit!(RecoveryProposed {
+            user: ctx.accounts.user_state.owner,
+            new_owner,
+            guardian,
+            timestamp: current_time,
+        });
+        
+        record_recovery_threshold(config, ctx.accounts.user_state.owner, current_time);
+        
+        Ok(())
+    }
+
+    /// Approve the pending recovery (signed by a guardian).
+    /// 
+    /// When the threshold is reached, the challenge period starts.
+    /// 
+    /// # Events
+    /// Emits `RecoveryApproved` (and `RecoveryChallengeStarted` at threshold)
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let config = &mut ctx.accounts.recovery_config;
+        let guardian = ctx.accounts.guardian.key();
+        
+        let index = config.guardian_index(&guardian).ok_or(CoreError::NotRecoveryGuardian)?;
+        require!(
+            config.pending_new_owner != Pubkey::default(),
+            CoreError::NoRecoveryPending
+        );
+        require!(
+            config.approvals & (1 << index) == 0,
+            CoreError::RecoveryAlreadyApproved
+        );
+        
+        config.approvals |= 1 << index;
+        
+        emit!(RecoveryApproved {
+            user: ctx.accounts.user_state.owner,
+            new_owner: config.pending_new_owner,
+            guardian,
+            approvals: config.approvals.count_ones() as u8,
+            threshold: config.threshold,
+            timestamp: current_time,
+        });
+        
+        record_recovery_threshold(config, ctx.accounts.user_state.owner, current_time);
+        
+        Ok(())
+    }
+
+    /// Veto the pending recovery (signed by the current wallet).
+    /// 
+    /// Possible at any point before the recovery is completed.
+    /// 
+    /// # Events
+    /// Emits `RecoveryVetoed`
+    pub fn veto_recovery(ctx: Context<VetoRecovery>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let config = &mut ctx.accounts.recovery_config;
+        
+        require!(
+            config.pending_new_owner != Pubkey::default(),
+            CoreError::NoRecoveryPending
+        );
+        
+        let vetoed_owner = config.pending_new_owner;
+        
+        config.pending_new_owner = Pubkey::default();
+        config.approvals = 0;
+        config.proposed_at = 0;
+        config.challenge_ends_at = 0;
+        
+        emit!(RecoveryVetoed {
+            user: ctx.accounts.owner.key(),
+            new_owner: vetoed_owner,
+            timestamp: current_time,
+        });
+        
+        msg!("Recovery vetoed by current owner");
+        
+        Ok(())
+    }
+
+    /// Complete an approved recovery after the challenge period.
+    /// 
+    /// Signed by the new wallet. Moves the UserState like
+    /// `complete_wallet_rotation` and closes the RecoveryConfig - the user
+    /// nominates guardians again from the new wallet.
+    /// 
+    /// # Events
+    /// Emits `RecoveryCompleted`
+    pub fn complete_recovery(ctx: Context<CompleteRecovery>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let config = &ctx.accounts.recovery_config;
+        
+        require!(
+            config.challenge_ends_at != 0 && current_time >= config.challenge_ends_at,
+            CoreError::RecoveryChallengeActive
+        );
+        
+        let old_user = &ctx.accounts.old_user_state;
+        let old_owner = old_user.owner;
+        let new_owner = ctx.accounts.new_owner.key();
+        
+        close_if_exists(
+            &ctx.accounts.wallet_rotation,
+            &ctx.accounts.new_owner.to_account_info(),
+        )?;
+        
+        let migrated = migrate_user_state(
+            old_user,
+            old_user.key(),
+            new_owner,
+            old_user.attestation,
+            old_user.attestation_issuer,
+            ctx.bumps.new_user_state,
+            current_time,
+        );
+        ctx.accounts.new_user_state.set_inner(migrated);
+        
+        ctx.accounts.biometric_record.user = new_owner;
+        
+        emit!(RecoveryCompleted {
+            old_owner,
+            new_owner,
+            approvals: config.approvals.count_ones() as u8,
+            timestamp: current_time,
+        });
+        
+        msg!("Account recovered to {}", new_owner);
+        
+        Ok(())
+    }
+
+    // ========================================================================
+    // FRAUD CHALLENGES (⚫ IMMUTABLE - duplicate-human detection)
+    // ========================================================================
+
+    /// Create the fraud challenge pool and its token vault (once).
+    /// 
+    /// Permissionless - the pool is a PDA controlled by the protocol.
+    pub fn initialize_challenge_pool(ctx: Context<InitializeChallengePool>) -> Result<()> {
+        let pool = &mut ctx.accounts.challenge_pool;
+        
+        pool.total_bonded = 0;
+        pool.total_forfeited = 0;
+        pool.total_rewarded = 0;
+        pool.total_challenges = 0;
+        pool.open_challenges = 0;
+        pool.bump = ctx.bumps.challenge_pool;
+        
+        msg!("Fraud challenge pool initialized");
+        
+        Ok(())
+    }
+
+    /// Accuse two accounts of belonging to the same human.
+    /// 
+    /// Exact biometric deduplication cannot catch a person enrolled with
+    /// two issuers (two different hashes). Anyone can challenge such a pair
+    /// by bonding `FRAUD_CHALLENGE_BOND` AGORA. The issuer of the newer
+    /// registration's attestation resolves it:
+    /// 
+    /// - Upheld: the newer registration is frozen, the challenger gets the
+    ///   bond back plus a reward from the pool
+    /// - Rejected: the bond is forfeited to the pool (funds future rewards)
+    /// 
+    /// `user_a` must sort before `user_b` so each pair has one open challenge
+    /// PDA. It is closed on resolution, so a pair can be challenged again.
+    /// 
+    /// # Events
+    /// Emits `FraudChallengeOpened`
+    pub fn open_fraud_challenge(ctx: Context<OpenFraudChallenge>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user_a = &ctx.accounts.user_a;
+        let user_b = &ctx.accounts.user_b;
+        
+        require!(user_a.key() < user_b.key(), CoreError::InvalidFraudChallenge);
+        require!(
+            user_a.is_verified && user_b.is_verified,
+            CoreError::InvalidFraudChallenge
+        );
+        
+        // ====================================================================
+        // LOCK BOND
+        // ====================================================================
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.challenger_token_account.to_account_info(),
+            to: ctx.accounts.challenge_vault.to_account_info(),
+            authority: ctx.accounts.challenger.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, FRAUD_CHALLENGE_BOND)?;
+        
+        // ====================================================================
+        // RECORD CHALLENGE
+        // ====================================================================
+        
+        let challenge = &mut ctx.accounts.fraud_challenge;
+        challenge.challenger = ctx.accounts.challenger.key();
+        challenge.user_a = user_a.key();
+        challenge.user_b = user_b.key();
+        challenge.bond = FRAUD_CHALLENGE_BOND;
+        challenge.opened_at = current_time;
+        challenge.status = ChallengeStatus::Pending;
+        challenge.resolver = Pubkey::default();
+        challenge.resolved_at = 0;
+        challenge.frozen_user = Pubkey::default();
+        challenge.bump = ctx.bumps.fraud_challenge;
+        
+        let pool = &mut ctx.accounts.challenge_pool;
+        pool.total_bonded += FRAUD_CHALLENGE_BOND;
+        pool.total_challenges += 1;
+        pool.open_challenges += 1;
+        
+        emit!(FraudChallengeOpened {
+            challenger: challenge.challenger,
+            user_a: challenge.user_a,
+            user_b: challenge.user_b,
+            bond: challenge.bond,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Resolve a fraud challenge (signed by a trusted issuer's authority).
+    /// 
+    /// Only the issuer that attested the newer of the two registrations (the
+    /// one that would be frozen) can resolve. The challenge record is closed
+    /// and its rent returned to the challenger.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Context with challenge, users and pool accounts
+    /// * `is_duplicate` - Whether both accounts belong to the same human
+    /// 
+    /// # Events
+    /// Emits `FraudChallengeResolved`
+    pub fn resolve_fraud_challenge(
+        ctx: Context<ResolveFraudChallenge>,
+        is_duplicate: bool,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        require_issuer_authority(&ctx.accounts.trusted_issuer, &ctx.accounts.resolver.key())?;
+        require!(
+            ctx.accounts.fraud_challenge.status == ChallengeStatus::Pending,
+            CoreError::ChallengeAlreadyResolved
+        );
+        
+        // The challenged attestation is the one of the newer registration
+        let challenged_issuer = if ctx.accounts.user_b.registration_timestamp
+            >= ctx.accounts.user_a.registration_timestamp
+        {
+            ctx.accounts.user_b.attestation_issuer
+        } else {
+            ctx.accounts.user_a.attestation_issuer
+        };
+        require_keys_eq!(
+            ctx.accounts.trusted_issuer_credential.key(),
+            challenged_issuer,
+            CoreError::UntrustedIssuer
+        );
+        
+        let bond = ctx.accounts.fraud_challenge.bond;
+        let pool = &mut ctx.accounts.challenge_pool;
+        pool.total_bonded -= bond;
+        pool.open_challenges -= 1;
+        
+        let mut payout = 0;
+        let mut frozen_user = Pubkey::default();
+        
+        if is_duplicate {
+            // ================================================================
+            // UPHELD - freeze the newer registration, reward challenger
+            // ================================================================
+            
+            let user_a = &mut ctx.accounts.user_a;
+            let user_b = &mut ctx.accounts.user_b;
+            let duplicate = if user_b.registration_timestamp >= user_a.registration_timestamp {
+                user_b
+            } else {
+                user_a
+            };
+            suspend_user_state(duplicate, SUSPENSION_REASON_DUPLICATE_IDENTITY, current_time);
+            frozen_user = duplicate.key();
+            
+            // Reward only from funds not backing other open bonds
+            let available = ctx.accounts.challenge_vault.amount
+                .saturating_sub(bond)
+                .saturating_sub(pool.total_bonded);
+            let reward = FRAUD_CHALLENGE_REWARD.min(available);
+            payout = bond + reward;
+            pool.total_rewarded += reward;
+            
+            let bump = [ctx.accounts.protocol_state.bump];
+            let seeds: &[&[u8]] = &[b"protocol", &bump];
+            let signer_seeds = &[seeds];
+            
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.challenge_vault.to_account_info(),
+                to: ctx.accounts.challenger_token_account.to_account_info(),
+                authority: ctx.accounts.protocol_state.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, payout)?;
+        } else {
+            // ================================================================
+            // REJECTED - bond stays in the pool
+            // ================================================================
+            
+            pool.total_forfeited += bond;
+        }
+        
+        // The record is closed on exit; the event is the permanent trace
+        let challenge = &ctx.accounts.fraud_challenge;
+        
+        emit!(FraudChallengeResolved {
+            challenger: challenge.challenger,
+            user_a: challenge.user_a,
+            user_b: challenge.user_b,
+            upheld: is_duplicate,
+            frozen_user,
+            payout,
+            resolver: ctx.accounts.resolver.key(),
+            timestamp: current_time,
+        });
+        
+        msg!(
+            "Fraud challenge {}. Payout: {}",
+            if is_duplicate { "upheld" } else { "rejected" },
+            payout
+        );
+        
+        Ok(())
+    }
+
+    // ========================================================================
+    // SUSPENSION & REINSTATEMENT (⚫ IMMUTABLE - due process)
+    // ========================================================================
+
+    /// Suspend a user by Governance order (permissionless crank).
+    /// 
+    /// Reads a UserOrder issued by an executed Sanction proposal in the
+    /// Governance program. A suspended user (`is_verified = false`) cannot
+    /// claim, transfer or vote. They may appeal within
+    /// `SUSPENSION_APPEAL_WINDOW`; reinstatement again needs an order.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Context with user and order accounts
+    /// * `proposal_id` - Proposal that issued the order
+    /// 
+    /// # Events
+    /// Emits `UserSuspended`
+    pub fn suspend_user(ctx: Context<ApplyUserOrder>, proposal_id: u64) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
+        
+        let reason_code = read_user_order(
+            &ctx.accounts.user_order,
+            user,
+            proposal_id,
+            USER_ORDER_SUSPEND,
+        )?;
+        
+        user.last_order_proposal_id = proposal_id;
+        suspend_user_state(user, reason_code, current_time);
+        
+        emit!(UserSuspended {
+            user: user.owner,
+            reason_code,
+            proposal_id,
+            appeal_deadline: user.appeal_deadline,
+            timestamp: current_time,
+        });
+        
+        msg!("User suspended by proposal #{}. Reason: {}", proposal_id, reason_code);
+        
+        Ok(())
+    }
+
+    /// Reinstate a suspended user by Governance order (permissionless crank).
+    /// 
+    /// # Arguments
+    /// * `ctx` - Context with user and order accounts
+    /// * `proposal_id` - Proposal that issued the order
+    /// 
+    /// # Events
+    /// Emits `UserReinstated`
+    pub fn reinstate_user(ctx: Context<ApplyUserOrder>, proposal_id: u64) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
+        
+        read_user_order(
+            &ctx.accounts.user_order,
+            user,
+            proposal_id,
+            USER_ORDER_REINSTATE,
+        )?;
+        require!(!user.is_verified, CoreError::UserNotSuspended);
+        
+        let previous_reason = user.suspension_reason;
+        
+        user.last_order_proposal_id = proposal_id;
+        user.is_verified = true;
+        user.suspension_reason = SUSPENSION_REASON_NONE;
+        user.suspended_at = 0;
+        user.appeal_deadline = 0;
+        user.appealed_at = 0;
+        
+        emit!(UserReinstated {
+            user: user.owner,
+            previous_reason,
+            proposal_id,
+            timestamp: current_time,
+        });
+        
+        msg!("User reinstated by proposal #{}", proposal_id);
+        
+        Ok(())
+    }
+
+    /// Appeal a suspension (signed by the suspended user).
+    /// 
+    /// Records the appeal on the account and emits an event for Governance
+    /// to act on. Only possible once, before the appeal deadline.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Context with user account
+    /// * `evidence_hash` - Hash of off-chain evidence (IPFS)
+    /// 
+    /// # Events
+    /// Emits `SuspensionAppealed`
+    pub fn appeal_suspension(
+        ctx: Context<AppealSuspension>,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
+        
+        require!(
+            !user.is_verified && user.suspension_reason != SUSPENSION_REASON_NONE,
+            CoreError::UserNotSuspended
+        );
+        require!(user.appealed_at == 0, CoreError::AppealNotAllowed);
+        require!(
+            current_time <= user.appeal_deadline,
+            CoreError::AppealNotAllowed
+        );
+        
+        user.appealed_at = current_time;
+        
+        emit!(SuspensionAppealed {
+            user: user.owner,
+            reason_code: user.suspension_reason,
+            evidence_hash,
+            timestamp: current_time,
+        });
+        
+        msg!("Suspension appealed");
+        
+        Ok(())
+    }
+
+    // ========================================================================
+    // DORMANT ACCOUNTS (⚫ IMMUTABLE - lapsed and deceased users)
+    // ========================================================================
+    // Active → Dormant (liveness expired beyond grace) → Closed (after the
+    // Governance `dormant_closure_period`). Verifying liveness while dormant
+    // makes the account active again.
+
+    /// Choose who receives the balance if the account is ever closed as dormant.
+    /// 
+    /// `Pubkey::default()` means the protocol treasury. Only the child
+    /// escrow goes to the heir unless the user also opts in with
+    /// `approve_dormant_transfer` for their wallet balance.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Context with user account
+    /// * `heir` - Heir wallet (default = treasury)
+    /// 
+    /// # Events
+    /// Emits `HeirSet`
+    pub fn set_heir(ctx: Context<SetHeir>, heir: Pubkey) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
+        
+        user.heir = heir;
+        
+        emit!(HeirSet {
+            user: user.owner,
+            heir: dormant_beneficiary(user, &ctx.accounts.protocol_state),
+            timestamp: current_time,
+        });
+        
+        msg!("Heir set");
+        
+        Ok(())
+    }
+    
+    /// Opt in to passing up to `amount` of the wallet balance to the heir
+    /// if the account is ever closed as dormant.
+    /// 
+    /// Approves the protocol as delegate of the user's token account for
+    /// `amount`; only `close_dormant_account` uses it. An SPL token account
+    /// has a single delegate: approving another delegate later (or revoking)
+    /// withdraws this opt-in, and this replaces any existing delegate.
+    /// 
+    /// # Events
+    /// Emits `DormantTransferApproved`
+    pub fn approve_dormant_transfer(
+        ctx: Context<ApproveDormantTransfer>,
+        amount: u64,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        let cpi_accounts = Approve {
+            to: ctx.accounts.user_token_account.to_account_info(),
+            delegate: ctx.accounts.protocol_state.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+        );
+        token::approve(cpi_ctx, amount)?;
+        
+        emit!(DormantTransferApproved {
+            user: ctx.accounts.owner.key(),
+            amount,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+    
+    /// Mark an account dormant once liveness has expired beyond grace
+    /// (anyone can call).
+    /// 
+    /// # Events
+    /// Emits `AccountDormant`
+    pub fn mark_dormant(ctx: Context<MarkDormant>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user_state;
+        
+        require!(user.dormant_since == 0, CoreError::AlreadyDormant);
+        require!(
+            liveness_status(user, current_time) == LivenessStatus::Expired,
+            CoreError::LivenessNotExpired
+        );
+        
+        user.dormant_since = current_time;
+        
+        emit!(AccountDormant {
+            user: user.owner,
+            liveness_expires_at: user.liveness_expires_at,
+            timestamp: current_time,
+        });
+        
+        msg!("Account marked dormant");
+        
+        Ok(())
+    }
+    
+    /// Close a dormant account after the Governance closure period
+    /// (anyone can call).
+    /// 
+    /// Balances go to the heir, or the treasury if none was set:
+    /// - the wallet balance, up to what the user approved
+    ///   (`approve_dormant_transfer`)
+    /// - the whole child escrow, which is then closed
+    /// 
+    /// The UserState, its recovery config and any pending wallet rotation
+    /// are closed, rent to the same beneficiary. The biometric record is
+    /// kept but marked retired, so the hash cannot register again
+    /// (Governance reads `is_retired` to clean up its own per-user accounts).
+    /// 
+    /// # Events
+    /// Emits `DormantAccountClosed`
+    pub fn close_dormant_account(ctx: Context<CloseDormantAccount>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let user = &ctx.accounts.user_state;
+        
+        require!(user.dormant_since != 0, CoreError::NotDormant);
+        let closure_period = read_dormant_closure_period(&ctx.accounts.core_parameters)?;
+        let closable_at = user.dormant_since
+            .checked_add(closure_period)
+            .ok_or(CoreError::DormantClosureLocked)?;
+        require!(current_time >= closable_at, CoreError::DormantClosureLocked);
+        
+        let protocol_key = ctx.accounts.protocol_state.key();
+        let bump = [ctx.accounts.protocol_state.bump];
+        let seeds: &[&[u8]] = &[b"protocol", &bump];
+        let signer_seeds = &[seeds];
+        
+        // ====================================================================
+        // WALLET BALANCE (only with the user's prior approval)
+        // ====================================================================
+        
+        let mut wallet_amount = 0;
+        let wallet_info = &ctx.accounts.user_token_account;
+        if *wallet_info.owner == token::ID && !wallet_info.data_is_empty() {
+            let user_token_account = TokenAccount::try_deserialize(
+                &mut &wallet_info.try_borrow_data()?[..]
+            )?;
+            if user_token_account.delegate == COption::Some(protocol_key) {
+                wallet_amount = user_token_account.amount.min(user_token_account.delegated_amount);
+            }
+            if wallet_amount > 0 {
+                let cpi_accounts = Transfer {
+                    from: wallet_info.to_account_info(),
+                    to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                    authority: ctx.accounts.protocol_state.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                );
+                token::transfer(cpi_ctx, wallet_amount)?;
+            }
+        }
+        
+        // ====================================================================
+        // CHILD ESCROW
+        // ====================================================================
+        
+        let mut escrow_amount = 0;
+        if user.child_escrow != Pubkey::default() {
+            let child_escrow = ctx.accounts.child_escrow
+                .as_ref()
+                .ok_or(CoreError::ChildEscrowRequired)?;
+            escrow_amount = child_escrow.amount;
+            
+            let cpi_accounts = Transfer {
+                from: child_escrow.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: ctx.accounts.protocol_state.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, escrow_amount)?;
+            
+            let cpi_accounts = CloseAccount {
+                account: child_escrow.to_account_info(),
+                destination: ctx.accounts.beneficiary.to_account_info(),
+                authority: ctx.accounts.protocol_state.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::close_account(cpi_ctx)?;
+        }
+        
+        // ====================================================================
+        // CLOSE PER-ACCOUNT PDAs
+        // ====================================================================
+        
+        close_if_exists(&ctx.accounts.recovery_config, &ctx.accounts.beneficiary)?;
+        close_if_exists(&ctx.accounts.wallet_rotation, &ctx.accounts.beneficiary)?;
+        
+        // ====================================================================
+        // RETIRE IDENTITY
+        // ====================================================================
+        
+        let bio_record = &mut ctx.accounts.biometric_record;
+        bio_record.is_registered = false;
+        bio_record.is_retired = true;
+        bio_record.invalidated_at = current_time;
+        
+        let protocol = &mut ctx.accounts.protocol_state;
+        protocol.total_users = protocol.total_users.saturating_sub(1);
+        
+        emit!(DormantAccountClosed {
+            user: ctx.accounts.user_state.owner,
+            beneficiary: ctx.accounts.beneficiary.key(),
+            wallet_amount,
+            escrow_amount,
+            timestamp: current_time,
+        });
+        
+        msg!("Dormant account closed");
+        
+        Ok(())
+    }
+}
+
+// ╔═══════════════════════════════════════════════════════════════════════════╗
+// ║                                                                           ║
+// ║                           ACCOUNT STRUCTURES                              ║
+// ║                                                                           ║
+// ╚═══════════════════════════════════════════════════════════════════════════╝
+
+// ============================================================================
+// PROTOCOL STATE
+// ============================================================================
+
+/// Global protocol state - singleton PDA.
+/// 
+/// Stores protocol-wide statistics and references.
+/// Created once during initialization.
+#[account]
+pub struct ProtocolState {
+    /// Original deployer authority (for reference only, no special powers)
+    pub authority: Pubkey,
+    
+    /// AGORA token mint address
+    pub mint: Pubkey,
+    
+    /// Protocol treasury address
+    pub treasury: Pubkey,
+    
+    /// Total registered users
+    pub total_users: u64,
+    
+    /// Total tokens ever minted (including burned)
+    pub total_minted: u128,
+    
+    /// Total tokens burned
+    pub total_burned: u128,
+    
+    /// Timestamp when protocol was launched
+    pub launch_timestamp: i64,
+    
+    /// Whether protocol is initialized
+    pub is_initialized: bool,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ProtocolState {
+    pub const SIZE: usize = 8 +  // Discriminator
+        32 +    // authority
+        32 +    // mint
+        32 +    // treasury
+        8 +     // total_users
+        16 +    // total_minted
+        16 +    // total_burned
+        8 +     // launch_timestamp
+        1 +     // is_initialized
+        1;      // bump
+}
+
+// ============================================================================
+// USER STATE
+// ============================================================================
+
+/// Individual user account state.
+/// 
+/// Created when a user registers. Tracks their claims, transactions,
+/// and verification status.
+#[account]
+pub struct UserState {
+    /// User's wallet address
+    pub owner: Pubkey,
+    
+    /// When user registered
+    pub registration_timestamp: i64,
+    
+    /// Last time user claimed UBI
+    pub last_claim_timestamp: i64,
+    
+    /// User's age in days at registration
+    pub age_in_days_at_registration: u64,
+    
+    /// User's citizenship (ISO 3166-1 alpha-3)
+    pub citizenship: [u8; 3],
+    
+    /// Whether user passed identity verification
+    pub is_verified: bool,
+    
+    /// Whether user is under 18
+    pub is_child: bool,
+    
+    /// Total AGORA ever claimed (for statistics)
+    pub total_claimed: u128,
+    
+    /// Tokens held in the child escrow (for children)
     pub locked_balance: u64,
     
-    /// Transaction count (for activity tracking)
-    pub transaction_count: u64,
+    /// Transaction count (for activity tracking)
+    pub transaction_count: u64,
+    
+    /// Last transaction timestamp (for rate limiting and activity)
+    pub last_transaction_timestamp: i64,
+    
+    /// When liveness was last verified
+    pub liveness_verified_at: i64,
+    
+    /// When liveness expires
+    pub liveness_expires_at: i64,
+    
+    // ========================================================================
+    // SAS ATTESTATION FIELDS (⚫ IMMUTABLE - part of core identity system)
+    // ========================================================================
+    
+    /// Current SAS attestation PDA (from Civic or other trusted issuer)
+    /// This is the on-chain proof of identity verification
+    pub attestation: Pubkey,
+    
+    /// When the attestation was last updated
+    /// Allows tracking attestation history
+    pub attestation_updated_at: i64,
+    
+    /// Number of times attestation has been updated
+    /// Useful for detecting unusual patterns
+    pub attestation_update_count: u8,
+    
+    /// Citizenships added after registration (each proven by attestation)
+    pub additional_citizenships: [[u8; 3]; MAX_ADDITIONAL_CITIZENSHIPS],
+    
+    /// Number of entries used in `additional_citizenships`
+    pub additional_citizenship_count: u8,
+    
+    /// When the primary citizenship was set (registration or last change)
+    pub citizenship_updated_at: i64,
+    
+    /// Number of times the primary citizenship has changed
+    pub citizenship_update_count: u8,
+    
+    // ========================================================================
+    // SUSPENSION FIELDS (⚫ IMMUTABLE - due process)
+    // ========================================================================
+    
+    /// Why the account is suspended (SUSPENSION_REASON_*, 0 = not suspended)
+    pub suspension_reason: u8,
+    
+    /// When the account was suspended
+    pub suspended_at: i64,
+    
+    /// Last moment an appeal can be filed
+    pub appeal_deadline: i64,
+    
+    /// When an appeal was filed (0 = none)
+    pub appealed_at: i64,
+    
+    /// Last Governance proposal whose order was applied (replay protection)
+    pub last_order_proposal_id: u64,
+    
+    /// When the attestation was flagged as lapsed (0 = valid or not checked)
+    pub attestation_lapsed_at: i64,
+    
+    /// SAS credential (issuer) of the current attestation.
+    /// Lets clients list users of a removed issuer with a memcmp filter
+    /// at offset 223.
+    pub attestation_issuer: Pubkey,
+    
+    /// Core-owned token account holding a child's UBI until 18
+    /// (["child_escrow", user_state] at registration; default = none)
+    pub child_escrow: Pubkey,
+    
+    /// Parent/guardian allowed to withdraw a monthly allowance from the
+    /// child escrow (default = none)
+    pub guardian: Pubkey,
+    
+    /// Start of the current allowance window
+    pub allowance_period_start: i64,
+    
+    /// Allowance withdrawn in the current window
+    pub allowance_withdrawn: u64,
+    
+    /// Wallet allowed to claim on the user's behalf (default = none)
+    /// ⚫ Governance reads this at offset 335
+    pub claim_delegate: Pubkey,
+    
+    /// Last claim made by the delegate (for gas pool reimbursement)
+    /// ⚫ Governance reads this at offset 367
+    pub last_delegated_claim_at: i64,
+    
+    /// When the last `LivenessWarning` was emitted
+    pub liveness_warned_at: i64,
+    
+    /// Receives the balance if the account is closed as dormant
+    /// (default = treasury)
+    pub heir: Pubkey,
+    
+    /// When the account was marked dormant (0 = active)
+    pub dormant_since: i64,
+    
+    /// UserState this account was moved from by rotation or recovery
+    /// (Governance orders issued against it still apply)
+    pub previous_user_state: Pubkey,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UserState {
+    pub const SIZE: usize = 8 +  // Discriminator
+        32 +    // owner
+        8 +     // registration_timestamp
+        8 +     // last_claim_timestamp
+        8 +     // age_in_days_at_registration
+        3 +     // citizenship
+        1 +     // is_verified
+        1 +     // is_child
+        16 +    // total_claimed
+        8 +     // locked_balance
+        8 +     // transaction_count
+        8 +     // last_transaction_timestamp
+        8 +     // liveness_verified_at
+        8 +     // liveness_expires_at
+        32 +    // attestation (SAS PDA)
+        8 +     // attestation_updated_at
+        1 +     // attestation_update_count
+        3 * MAX_ADDITIONAL_CITIZENSHIPS + // additional_citizenships
+        1 +     // additional_citizenship_count
+        8 +     // citizenship_updated_at
+        1 +     // citizenship_update_count
+        1 +     // suspension_reason
+        8 +     // suspended_at
+        8 +     // appeal_deadline
+        8 +     // appealed_at
+        8 +     // last_order_proposal_id
+        8 +     // attestation_lapsed_at
+        32 +    // attestation_issuer
+        32 +    // child_escrow
+        32 +    // guardian
+        8 +     // allowance_period_start
+        8 +     // allowance_withdrawn
+        32 +    // claim_delegate
+        8 +     // last_delegated_claim_at
+        8 +     // liveness_warned_at
+        32 +    // heir
+        8 +     // dormant_since
+        32 +    // previous_user_state
+        1;      // bump
+    
+    // Field offsets read by Governance (⚫ IMMUTABLE - new fields go before `bump`)
+    pub const OWNER_OFFSET: usize = 8;
+    pub const IS_VERIFIED_OFFSET: usize = 67;
+    pub const ATTESTATION_OFFSET: usize = 125;
+    pub const CLAIM_DELEGATE_OFFSET: usize = 335;
+    pub const LAST_DELEGATED_CLAIM_OFFSET: usize = 367;
+}
+
+// ============================================================================
+// BIOMETRIC REGISTRY
+// ============================================================================
+
+/// Global registry tracking total biometric registrations.
+/// 
+/// This is a singleton PDA that tracks statistics only.
+/// Individual biometric hashes are stored in BiometricRecord PDAs.
+#[account]
+pub struct BiometricRegistry {
+    /// Total number of registered biometrics
+    pub total_registered: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BiometricRegistry {
+    pub const SIZE: usize = 8 +  // Discriminator
+        8 +     // total_registered
+        1;      // bump
+}
+
+/// Individual biometric record.
+/// 
+/// PDA derived from biometric hash - ensures uniqueness.
+/// If a record exists for a hash, that human is already registered.
+#[account]
+pub struct BiometricRecord {
+    /// The biometric hash (SHA-256 of fingerprint, iris, DNA, etc.)
+    pub hash: [u8; 32],
+    
+    /// The user this biometric belongs to
+    pub user: Pubkey,
+    
+    /// When this biometric was registered
+    pub registered_at: i64,
+    
+    /// Whether this is a valid registration (false if superseded)
+    pub is_registered: bool,
+    
+    /// When this record was invalidated (0 if still active)
+    pub invalidated_at: i64,
+    
+    /// Previous biometric hash (if this is an update)
+    pub previous_hash: Option<[u8; 32]>,
+    
+    /// Whether the account was closed as dormant (hash cannot register again)
+    /// (⚫ Governance reads this to close its per-user accounts)
+    pub is_retired: bool,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BiometricRecord {
+    pub const SIZE: usize = 8 +  // Discriminator
+        32 +    // hash
+        32 +    // user
+        8 +     // registered_at
+        1 +     // is_registered
+        8 +     // invalidated_at
+        1 + 32 + // previous_hash (Option)
+        1 +     // is_retired
+        1;      // bump
+}
+
+// ============================================================================
+// WALLET ROTATION
+// ============================================================================
+
+/// Pending move of a UserState to a new wallet.
+/// 
+/// PDA: ["rotation", user_state]. Closed on cancel or completion.
+#[account]
+pub struct WalletRotation {
+    /// UserState being moved
+    pub user_state: Pubkey,
+    
+    /// Current wallet (can cancel during cooldown)
+    pub old_owner: Pubkey,
+    
+    /// Wallet the account moves to
+    pub new_owner: Pubkey,
+    
+    /// SAS attestation tied to the new wallet
+    pub new_attestation: Pubkey,
+    
+    /// Credential (issuer) of the new attestation
+    pub new_attestation_issuer: Pubkey,
+    
+    /// When the rotation was requested
+    pub requested_at: i64,
+    
+    /// Earliest completion time
+    pub executable_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl WalletRotation {
+    pub const SIZE: usize = 8 +  // Discriminator
+        32 +    // user_state
+        32 +    // old_owner
+        32 +    // new_owner
+        32 +    // new_attestation
+        32 +    // new_attestation_issuer
+        8 +     // requested_at
+        8 +     // executable_at
+        1;      // bump
+}
+
+// ============================================================================
+// RECOVERY CONFIG
+// ============================================================================
+
+/// Social recovery guardians for one UserState.
+/// 
+/// PDA: ["recovery", user_state]
+#[account]
+pub struct RecoveryConfig {
+    /// UserState protected by this config
+    pub user_state: Pubkey,
+    
+    /// Guardian wallets (verified humans)
+    pub guardians: [Pubkey; MAX_RECOVERY_GUARDIANS],
+    
+    /// Number of guardians set
+    pub guardian_count: u8,
+    
+    /// Approvals required to start the challenge period
+    pub threshold: u8,
+    
+    /// Wallet the pending recovery moves to (default = none pending)
+    pub pending_new_owner: Pubkey,
+    
+    /// Bitmap of guardians that approved the pending recovery
+    pub approvals: u8,
+    
+    /// When the pending recovery was proposed
+    pub proposed_at: i64,
+    
+    /// End of the challenge period (0 = threshold not reached)
+    pub challenge_ends_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RecoveryConfig {
+    pub const SIZE: usize = 8 +  // Discriminator
+        32 +    // user_state
+        32 * MAX_RECOVERY_GUARDIANS + // guardians
+        1 +     // guardian_count
+        1 +     // threshold
+        32 +    // pending_new_owner
+        1 +     // approvals
+        8 +     // proposed_at
+        8 +     // challenge_ends_at
+        1;      // bump
+    
+    /// Position of `guardian` in the guardian list
+    pub fn guardian_index(&self, guardian: &Pubkey) -> Option<usize> {
+        self.guardians[..self.guardian_count as usize]
+            .iter()
+            .position(|g| g == guardian)
+    }
+    
+    /// Whether the pending recovery has stalled and can be replaced
+    pub fn is_stale(&self, current_time: i64) -> bool {
+        let since = if self.challenge_ends_at == 0 {
+            self.proposed_at
+        } else {
+            self.challenge_ends_at
+        };
+        current_time >= since.saturating_add(RECOVERY_PROPOSAL_EXPIRY)
+    }
+}
+
+// ============================================================================
+// FRAUD CHALLENGES
+// ============================================================================
+
+/// Global fraud challenge pool - singleton PDA.
+/// 
+/// Token vault ["challenge_vault"] holds open bonds plus forfeited bonds;
+/// only the forfeited part is paid out as rewards.
+#[account]
+pub struct ChallengePool {
+    /// Bonds locked by open challenges
+    pub total_bonded: u64,
+    
+    /// Bonds forfeited by rejected challenges
+    pub total_forfeited: u64,
+    
+    /// Rewards paid to successful challengers
+    pub total_rewarded: u64,
+    
+    /// Challenges ever opened
+    pub total_challenges: u64,
+    
+    /// Challenges awaiting resolution
+    pub open_challenges: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ChallengePool {
+    pub const SIZE: usize = 8 +  // Discriminator
+        8 +     // total_bonded
+        8 +     // total_forfeited
+        8 +     // total_rewarded
+        8 +     // total_challenges
+        8 +     // open_challenges
+        1;      // bump
+}
+
+/// Accusation that two UserStates are the same human.
+/// 
+/// PDA: ["fraud_challenge", user_a, user_b] with user_a < user_b
+#[account]
+pub struct FraudChallenge {
+    /// Wallet that bonded the challenge
+    pub challenger: Pubkey,
+    
+    /// Accused UserState accounts
+    pub user_a: Pubkey,
+    pub user_b: Pubkey,
+    
+    /// Bond locked in the vault
+    pub bond: u64,
+    
+    /// When the challenge was opened
+    pub opened_at: i64,
+    
+    /// Current status
+    pub status: ChallengeStatus,
+    
+    /// Trusted issuer authority that resolved it
+    pub resolver: Pubkey,
+    
+    /// When it was resolved (0 = pending)
+    pub resolved_at: i64,
+    
+    /// UserState frozen by an upheld challenge
+    pub frozen_user: Pubkey,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FraudChallenge {
+    pub const SIZE: usize = 8 +  // Discriminator
+        32 +    // challenger
+        32 +    // user_a
+        32 +    // user_b
+        8 +     // bond
+        8 +     // opened_at
+        1 +     // status
+        32 +    // resolver
+        8 +     // resolved_at
+        32 +    // frozen_user
+        1;      // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengeStatus {
+    Pending,
+    Upheld,
+    Rejected,
+}
+
+// ============================================================================
+// ISSUER STATS
+// ============================================================================
+
+/// Registration counters per trusted issuer, enforcing Governance quotas.
+/// 
+/// PDA: ["issuer_stats", credential]. Governance copies
+/// `total_registrations` into TrustedIssuer.total_attestations.
+#[account]
+pub struct IssuerStats {
+    /// SAS credential of the issuer (⚫ Governance reads this at offset 8)
+    pub credential: Pubkey,
+    
+    /// Registrations accepted (⚫ Governance reads this at offset 40)
+    pub total_registrations: u64,
+    
+    /// Registrations refused for exceeding quotas
+    pub total_refused: u64,
+    
+    /// Start of the current daily window
+    pub day_start: i64,
+    
+    /// Registrations accepted / attempted in the current day
+    pub day_registrations: u32,
+    pub day_attempts: u32,
+    
+    /// Start of the current weekly window
+    pub week_start: i64,
+    
+    /// Registrations accepted / attempted in the current week
+    pub week_registrations: u32,
+    pub week_attempts: u32,
+    
+    /// When the issuer was automatically suspended (0 = not suspended)
+    pub suspended_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl IssuerStats {
+    pub const SIZE: usize = 8 +  // Discriminator
+        32 +    // credential
+        8 +     // total_registrations
+        8 +     // total_refused
+        8 +     // day_start
+        4 +     // day_registrations
+        4 +     // day_attempts
+        8 +     // week_start
+        4 +     // week_registrations
+        4 +     // week_attempts
+        8 +     // suspended_at
+        1;      // bump
+}
+
+/// Marks an attestation already refused for exceeding its issuer's quotas.
+/// 
+/// PDA: ["registration_refusal", attestation]. Retrying the same
+/// attestation fails instead of counting towards issuer suspension again.
+#[account]
+pub struct RegistrationRefusal {
+    /// Refused SAS attestation
+    pub attestation: Pubkey,
+    
+    /// Issuer credential whose quota was exceeded
+    pub issuer: Pubkey,
+    
+    /// When the attestation was first refused
+    pub refused_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RegistrationRefusal {
+    pub const SIZE: usize = 8 +  // Discriminator
+        32 +    // attestation
+        32 +    // issuer
+        8 +     // refused_at
+        1;      // bump
+}
+
+/// Outcome for one user in a `batch_claim`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BatchClaimStatus {
+    Claimed,
+    /// Wallet did not sign and kiosk is not the user's claim delegate
+    Unauthorized,
+    /// Destination, sanction, attestation or issuer account mismatch
+    InvalidAccounts,
+    /// Claim rejected (nothing to claim, liveness, attestation, suspension...)
+    Failed,
+}
+
+/// Per-user result returned by `batch_claim`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchClaimResult {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub status: BatchClaimStatus,
+}
+
+/// Liveness status returned by `get_liveness_status`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum LivenessStatus {
+    /// More than 60 days before expiry
+    Valid,
+    /// Within 60 days of expiry - user should re-verify
+    Warning,
+    /// Expired, but claims still allowed for 30 days
+    Grace,
+    /// Claims blocked until `verify_liveness`
+    Expired,
+}
+
+/// Result of `preview_claim`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimPreview {
+    /// Whole days claimable now (max 30)
+    pub claimable_days: u64,
+    /// Amount before sanctions
+    pub base_amount: u64,
+    /// UBI percentage after sanctions (100 = none)
+    pub sanction_percentage: u64,
+    /// Amount that would be minted
+    pub actual_amount: u64,
+    /// Last moment a claim is allowed without new liveness verification
+    pub liveness_deadline: i64,
+    /// Days beyond the accumulation window, lost on the next claim
+    pub forfeited_days: u64,
+    /// Tokens would go to the child escrow
+    pub is_locked: bool,
+    /// Verified, live and something to claim (attestation not checked)
+    pub can_claim: bool,
+}
+
+// ╔═══════════════════════════════════════════════════════════════════════════╗
+// ║                                                                           ║
+// ║                          CONTEXT STRUCTURES                               ║
+// ║                                                                           ║
+// ╚═══════════════════════════════════════════════════════════════════════════╝
+
+// ============================================================================
+// INITIALIZE CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    /// Deployer (pays for account creation)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Protocol state PDA
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolState::SIZE,
+        seeds = [b"protocol"],
+        bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    /// Biometric registry PDA
+    #[account(
+        init,
+        payer = authority,
+        space = BiometricRegistry::SIZE,
+        seeds = [b"biometric_registry"],
+        bump
+    )]
+    pub biometric_registry: Account<'info, BiometricRegistry>,
+    
+    /// AGORA token mint
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = TOKEN_DECIMALS,
+        mint::authority = protocol_state,
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// Treasury token account
+    /// CHECK: Created by associated token program
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// ============================================================================
+// REGISTER USER CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(age_in_days: u64, biometric_hash: [u8; 32], citizenship: [u8; 3])]
+pub struct RegisterUser<'info> {
+    /// User registering
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// User state PDA
+    #[account(
+        init,
+        payer = owner,
+        space = UserState::SIZE,
+        seeds = [b"user", owner.key().as_ref()],
+        bump
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Biometric record PDA (derived from hash)
+    #[account(
+        init,
+        payer = owner,
+        space = BiometricRecord::SIZE,
+        seeds = [b"biometric", biometric_hash.as_ref()],
+        bump
+    )]
+    pub biometric_record: Account<'info, BiometricRecord>,
+    
+    /// Biometric registry (to update count)
+    #[account(
+        mut,
+        seeds = [b"biometric_registry"],
+        bump = biometric_registry.bump
+    )]
+    pub biometric_registry: Account<'info, BiometricRegistry>,
+    
+    /// Protocol state
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    /// Token mint
+    #[account(
+        mut,
+        address = protocol_state.mint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// User's token account
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    /// Country sanction PDA from Governance program (may be uninitialized)
+    /// If this account exists and is active, UBI is reduced
+    /// CHECK: We verify this is from Governance program and parse manually
+    #[account(
+        seeds = [b"sanction", citizenship.as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID,
+    )]
+    pub country_sanction: AccountInfo<'info>,
+    
+    /// SAS Attestation from Civic (or other trusted issuer)
+    /// This proves the user has passed identity verification
+    /// CHECK: Verified manually - must be valid, not expired, from trusted issuer
+    pub attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - must be active trusted issuer
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// The credential pubkey used to derive trusted_issuer PDA
+    /// CHECK: Used only for PDA derivation
+    pub trusted_issuer_credential: AccountInfo<'info>,
+    
+    /// Escrow for a child's tokens (required for a child, omitted for adults)
+    #[account(
+        init,
+        payer = owner,
+        token::mint = mint,
+        token::authority = protocol_state,
+        seeds = [b"child_escrow", user_state.key().as_ref()],
+        bump
+    )]
+    pub child_escrow: Option<Account<'info, TokenAccount>>,
+    
+    /// Registration counters for this issuer (created on first registration)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = IssuerStats::SIZE,
+        seeds = [b"issuer_stats", trusted_issuer_credential.key().as_ref()],
+        bump
+    )]
+    pub issuer_stats: Account<'info, IssuerStats>,
+    
+    /// Refusal record for this attestation (required to record an
+    /// over-quota refusal; closed again when the registration succeeds)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = RegistrationRefusal::SIZE,
+        seeds = [b"registration_refusal", attestation.key().as_ref()],
+        bump
+    )]
+    pub registration_refusal: Option<Account<'info, RegistrationRefusal>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// ============================================================================
+// CLAIM DAILY CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimDaily<'info> {
+    /// User claiming
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Protocol state
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    /// Token mint
+    #[account(
+        mut,
+        address = protocol_state.mint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// User's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    /// Country sanction PDA for the user's citizenship (may be uninitialized).
+    /// Always required so a sanction cannot be skipped by omitting it.
+    /// Sanction PDAs for additional citizenships follow as remaining accounts.
+    /// CHECK: PDA verified against Governance program, parsed manually
+    #[account(
+        seeds = [b"sanction", user_state.citizenship.as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID,
+    )]
+    pub country_sanction: AccountInfo<'info>,
+    
+    /// User's current SAS attestation (re-validated on every claim)
+    /// CHECK: Must be user_state.attestation, verified manually
+    #[account(address = user_state.attestation @ CoreError::InvalidAttestation)]
+    pub attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - must be active trusted issuer
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// The credential pubkey used to derive trusted_issuer PDA
+    /// CHECK: Used only for PDA derivation
+    pub trusted_issuer_credential: AccountInfo<'info>,
+    
+    /// Child escrow (required while the user is a child)
+    #[account(
+        mut,
+        address = user_state.child_escrow @ CoreError::ChildEscrowRequired
+    )]
+    pub child_escrow: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// CLAIM DELEGATE CONTEXTS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetClaimDelegate<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDailyDelegated<'info> {
+    /// Delegate claiming on the user's behalf
+    pub delegate: Signer<'info>,
+    
+    /// User state
+    #[account(
+        mut,
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.claim_delegate == delegate.key() @ CoreError::InvalidClaimDelegate
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Protocol state
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    /// Token mint
+    #[account(
+        mut,
+        address = protocol_state.mint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// User's own token account (never the delegate's)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user_state.owner,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    /// Country sanction PDA for the user's citizenship (may be uninitialized).
+    /// Sanction PDAs for additional citizenships follow as remaining accounts.
+    /// CHECK: PDA verified against Governance program, parsed manually
+    #[account(
+        seeds = [b"sanction", user_state.citizenship.as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID,
+    )]
+    pub country_sanction: AccountInfo<'info>,
+    
+    /// User's current SAS attestation (re-validated on every claim)
+    /// CHECK: Must be user_state.attestation, verified manually
+    #[account(address = user_state.attestation @ CoreError::InvalidAttestation)]
+    pub attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - must be active trusted issuer
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// The credential pubkey used to derive trusted_issuer PDA
+    /// CHECK: Used only for PDA derivation
+    pub trusted_issuer_credential: AccountInfo<'info>,
+    
+    /// Child escrow (required while the user is a child)
+    #[account(
+        mut,
+        address = user_state.child_escrow @ CoreError::ChildEscrowRequired
+    )]
+    pub child_escrow: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// LIVENESS STATUS CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct GetLivenessStatus<'info> {
+    #[account(
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump
+    )]
+    pub user_state: Account<'info, UserState>,
+}
+
+// ============================================================================
+// PREVIEW CLAIM CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct PreviewClaim<'info> {
+    /// User state to preview
+    #[account(
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Country sanction PDA for the user's citizenship (may be uninitialized)
+    /// CHECK: PDA verified against Governance program, parsed manually
+    #[account(
+        seeds = [b"sanction", user_state.citizenship.as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID,
+    )]
+    pub country_sanction: AccountInfo<'info>,
+}
+
+// ============================================================================
+// BATCH CLAIM CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct BatchClaim<'info> {
+    /// Kiosk / NGO operator submitting the batch
+    pub kiosk: Signer<'info>,
+    
+    /// Protocol state
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    /// Token mint
+    #[account(
+        mut,
+        address = protocol_state.mint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// UNLOCK ADULT CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UnlockAdult<'info> {
+    /// User coming of age
+    #[account(
+        mut,
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump
+    )]
+    pub user_state: Account<'info, UserState>,
+}
+
+// ============================================================================
+// CHILD GUARDIAN CONTEXTS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct LinkChildGuardian<'info> {
+    /// Parent or legal guardian
+    pub guardian: Signer<'info>,
+    
+    /// Guardian must be a verified adult user
+    #[account(
+        seeds = [b"user", guardian.key().as_ref()],
+        bump = guardian_state.bump,
+        constraint = guardian_state.owner == guardian.key() @ CoreError::Unauthorized,
+        constraint = guardian_state.is_verified @ CoreError::UserNotVerified,
+        constraint = !guardian_state.is_child @ CoreError::InvalidGuardianship
+    )]
+    pub guardian_state: Account<'info, UserState>,
+    
+    /// Child's user state
+    #[account(
+        mut,
+        seeds = [b"user", child_state.owner.as_ref()],
+        bump = child_state.bump
+    )]
+    pub child_state: Account<'info, UserState>,
+    
+    /// Current guardian (required to consent when replacing them)
+    pub current_guardian: Option<Signer<'info>>,
+    
+    /// SAS guardianship attestation (issued to the guardian, names the child)
+    /// CHECK: Verified manually - valid SAS attestation from trusted issuer
+    pub attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - must be active trusted issuer
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// The credential pubkey used to derive trusted_issuer PDA
+    /// CHECK: Used only for PDA derivation
+    pub trusted_issuer_credential: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawChildAllowance<'info> {
+    /// Linked guardian
+    pub guardian: Signer<'info>,
+    
+    /// Child's user state
+    #[account(
+        mut,
+        seeds = [b"user", child_state.owner.as_ref()],
+        bump = child_state.bump,
+        constraint = child_state.guardian == guardian.key() @ CoreError::NotChildGuardian
+    )]
+    pub child_state: Account<'info, UserState>,
+    
+    /// Governance CoreParameters (allowance cap)
+    /// CHECK: PDA verified against Governance program, parsed manually
+    #[account(
+        seeds = [b"core_parameters"],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub core_parameters: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    #[account(
+        mut,
+        address = child_state.child_escrow @ CoreError::ChildEscrowRequired
+    )]
+    pub child_escrow: Account<'info, TokenAccount>,
+    
+    /// Guardian's token account
+    #[account(
+        mut,
+        token::mint = child_escrow.mint,
+        token::authority = guardian,
+    )]
+    pub guardian_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// RELEASE CHILD ESCROW CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ReleaseChildEscrow<'info> {
+    /// Anyone can trigger the release
+    pub caller: Signer<'info>,
+    
+    /// User coming of age
+    #[account(
+        mut,
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump,
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// User's wallet (receives the escrow rent)
+    /// CHECK: Must be user_state.owner
+    #[account(mut, address = user_state.owner @ CoreError::Unauthorized)]
+    pub owner: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    #[account(
+        mut,
+        address = user_state.child_escrow @ CoreError::ChildEscrowRequired
+    )]
+    pub child_escrow: Account<'info, TokenAccount>,
+    
+    /// User's token account
+    #[account(
+        mut,
+        associated_token::mint = child_escrow.mint,
+        associated_token::authority = owner,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// VERIFY LIVENESS CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct VerifyLiveness<'info> {
+    /// User verifying liveness
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Biometric record (to verify hash matches)
+    #[account(
+        seeds = [b"biometric", biometric_record.hash.as_ref()],
+        bump = biometric_record.bump,
+        constraint = biometric_record.user == owner.key() @ CoreError::Unauthorized
+    )]
+    pub biometric_record: Account<'info, BiometricRecord>,
+}
+
+// ============================================================================
+// UPDATE BIOMETRIC CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(old_biometric_proof: [u8; 32], new_biometric_hash: [u8; 32])]
+pub struct UpdateBiometric<'info> {
+    /// User updating their biometric
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Old biometric record (to be invalidated)
+    #[account(
+        mut,
+        seeds = [b"biometric", old_biometric_record.hash.as_ref()],
+        bump = old_biometric_record.bump,
+        constraint = old_biometric_record.user == owner.key() @ CoreError::Unauthorized
+    )]
+    pub old_biometric_record: Account<'info, BiometricRecord>,
+    
+    /// New biometric record (to be created)
+    #[account(
+        init,
+        payer = owner,
+        space = BiometricRecord::SIZE,
+        seeds = [b"biometric", new_biometric_hash.as_ref()],
+        bump
+    )]
+    pub new_biometric_record: Account<'info, BiometricRecord>,
+    
+    /// Biometric registry (for statistics)
+    #[account(
+        mut,
+        seeds = [b"biometric_registry"],
+        bump = biometric_registry.bump
+    )]
+    pub biometric_registry: Account<'info, BiometricRegistry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// TRANSFER TOKENS CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct TransferTokens<'info> {
+    /// Sender
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    
+    /// Sender state
+    #[account(
+        mut,
+        seeds = [b"user", sender.key().as_ref()],
+        bump = sender_state.bump,
+        constraint = sender_state.owner == sender.key() @ CoreError::Unauthorized
+    )]
+    pub sender_state: Account<'info, UserState>,
+    
+    /// Recipient
+    /// CHECK: Just receiving tokens
+    pub recipient: AccountInfo<'info>,
+    
+    /// Protocol state
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    /// Token mint
+    #[account(
+        mut,
+        address = protocol_state.mint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// Sender's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = sender,
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+    
+    /// Recipient's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    
+    /// Treasury token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = protocol_state.treasury,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Optional: Fee state from Governance program
+    /// CHECK: Verified and parsed manually
+    pub fee_state: Option<AccountInfo<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// UPDATE ATTESTATION CONTEXT (⚫ IMMUTABLE - enables technology migration)
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateAttestation<'info> {
+    /// User updating their attestation
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// User state - contains current attestation reference
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// New SAS attestation account
+    /// CHECK: Verified manually in instruction - must be valid SAS attestation
+    /// from trusted issuer, linked to this wallet, not expired, not revoked
+    pub new_attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - must be active trusted issuer
+    /// PDA seeds: ["trusted_issuer", credential.as_ref()]
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// The credential pubkey used to derive trusted_issuer PDA
+    /// CHECK: Used only for PDA derivation
+    pub trusted_issuer_credential: AccountInfo<'info>,
+    
+    /// SAS Program (for account ownership verification)
+    /// CHECK: Verified against known SAS program ID
+    #[account(
+        constraint = sas_program.key() == SAS_PROGRAM_ID @ CoreError::InvalidAttestation
+    )]
+    pub sas_program: AccountInfo<'info>,
+}
+
+// ============================================================================
+// FLAG LAPSED ATTESTATION CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct FlagLapsedAttestation<'info> {
+    /// User state being checked
+    #[account(
+        mut,
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// User's current SAS attestation (may be closed)
+    /// CHECK: Must be user_state.attestation, verified manually
+    #[account(address = user_state.attestation @ CoreError::InvalidAttestation)]
+    pub attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account of the attestation's credential
+    /// CHECK: Verified manually (may be deactivated or missing)
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// The attestation's credential, used to derive trusted_issuer PDA
+    /// CHECK: Checked against the attestation's credential
+    pub trusted_issuer_credential: AccountInfo<'info>,
+}
+
+// ============================================================================
+// ADD CITIZENSHIP CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct AddCitizenship<'info> {
+    /// User adding a citizenship
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// SAS attestation proving the citizenship
+    /// CHECK: Verified manually - valid SAS attestation from trusted issuer,
+    /// linked to this wallet, carrying the country code
+    pub attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - must be active trusted issuer
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// The credential pubkey used to derive trusted_issuer PDA
+    /// CHECK: Used only for PDA derivation
+    pub trusted_issuer_credential: AccountInfo<'info>,
+}
+
+// ============================================================================
+// UPDATE CITIZENSHIP CONTEXT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateCitizenship<'info> {
+    /// User changing citizenship
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// SAS attestation proving the new citizenship
+    /// CHECK: Verified manually - valid SAS attestation from trusted issuer,
+    /// linked to this wallet, carrying the country code
+    pub attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - must be active trusted issuer
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// The credential pubkey used to derive trusted_issuer PDA
+    /// CHECK: Used only for PDA derivation
+    pub trusted_issuer_credential: AccountInfo<'info>,
+}
+
+// ============================================================================
+// WALLET ROTATION CONTEXTS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RequestWalletRotation<'info> {
+    /// New wallet (pays for the rotation record)
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+    
+    /// User state being moved
+    #[account(
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Biometric record of the user
+    #[account(
+        seeds = [b"biometric", biometric_record.hash.as_ref()],
+        bump = biometric_record.bump,
+        constraint = biometric_record.user == user_state.owner @ CoreError::Unauthorized,
+        constraint = biometric_record.is_registered @ CoreError::Unauthorized
+    )]
+    pub biometric_record: Account<'info, BiometricRecord>,
+    
+    /// Pending rotation record
+    #[account(
+        init,
+        payer = new_owner,
+        space = WalletRotation::SIZE,
+        seeds = [b"rotation", user_state.key().as_ref()],
+        bump
+    )]
+    pub wallet_rotation: Account<'info, WalletRotation>,
+    
+    /// UserState PDA of the new wallet (must not exist yet)
+    /// CHECK: Only checked to be empty
+    #[account(
+        seeds = [b"user", new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_user_state: AccountInfo<'info>,
+    
+    /// SAS attestation tied to the new wallet
+    /// CHECK: Verified manually - valid SAS attestation from trusted issuer
+    pub attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - must be active trusted issuer
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// The credential pubkey used to derive trusted_issuer PDA
+    /// CHECK: Used only for PDA derivation
+    pub trusted_issuer_credential: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelWalletRotation<'info> {
+    /// Current wallet
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Pending rotation (rent returned to the requester)
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [b"rotation", user_state.key().as_ref()],
+        bump = wallet_rotation.bump
+    )]
+    pub wallet_rotation: Account<'info, WalletRotation>,
+    
+    /// Requester of the rotation
+    /// CHECK: Must match wallet_rotation.new_owner, only receives rent
+    #[account(mut, address = wallet_rotation.new_owner)]
+    pub new_owner: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CompleteWalletRotation<'info> {
+    /// New wallet
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+    
+    /// Old user state (closed, rent to new wallet)
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [b"user", old_user_state.owner.as_ref()],
+        bump = old_user_state.bump
+    )]
+    pub old_user_state: Account<'info, UserState>,
+    
+    /// Pending rotation (closed)
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [b"rotation", old_user_state.key().as_ref()],
+        bump = wallet_rotation.bump,
+        constraint = wallet_rotation.new_owner == new_owner.key() @ CoreError::Unauthorized
+    )]
+    pub wallet_rotation: Account<'info, WalletRotation>,
+    
+    /// New user state PDA
+    #[account(
+        init,
+        payer = new_owner,
+        space = UserState::SIZE,
+        seeds = [b"user", new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_user_state: Account<'info, UserState>,
+    
+    /// Biometric record (re-linked to the new wallet)
+    #[account(
+        mut,
+        seeds = [b"biometric", biometric_record.hash.as_ref()],
+        bump = biometric_record.bump,
+        constraint = biometric_record.user == old_user_state.owner @ CoreError::Unauthorized,
+        constraint = biometric_record.is_registered @ CoreError::Unauthorized
+    )]
+    pub biometric_record: Account<'info, BiometricRecord>,
+    
+    /// Identity attestation recorded in the rotation (re-checked)
+    /// CHECK: Verified manually - valid SAS attestation from trusted issuer
+    #[account(address = wallet_rotation.new_attestation @ CoreError::InvalidAttestation)]
+    pub attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - must be active trusted issuer
+    #[account(
+        seeds = [b"trusted_issuer", wallet_rotation.new_attestation_issuer.as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
+    
+    /// Recovery config of the old user state (closed if it exists)
+    /// CHECK: PDA verified, closed only if owned by this program
+    #[account(
+        mut,
+        seeds = [b"recovery", old_user_state.key().as_ref()],
+        bump
+    )]
+    pub recovery_config: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// SOCIAL RECOVERY CONTEXTS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ConfigureRecovery<'info> {
+    /// User nominating guardians
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Recovery config PDA
+    #[account(
+        init,
+        payer = owner,
+        space = RecoveryConfig::SIZE,
+        seeds = [b"recovery", user_state.key().as_ref()],
+        bump
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
     
-    /// Last transaction timestamp (for rate limiting and activity)
-    pub last_transaction_timestamp: i64,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRecoveryGuardians<'info> {
+    /// User changing guardians
+    pub owner: Signer<'info>,
     
-    /// When liveness was last verified
-    pub liveness_verified_at: i64,
+    /// User state
+    #[account(
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
     
-    /// When liveness expires
-    pub liveness_expires_at: i64,
+    /// Recovery config
+    #[account(
+        mut,
+        seeds = [b"recovery", user_state.key().as_ref()],
+        bump = recovery_config.bump
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct ProposeRecovery<'info> {
+    /// Guardian starting the recovery
+    pub guardian: Signer<'info>,
     
-    // ========================================================================
-    // SAS ATTESTATION FIELDS (⚫ IMMUTABLE - part of core identity system)
-    // ========================================================================
+    /// User state being recovered
+    #[account(
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump
+    )]
+    pub user_state: Account<'info, UserState>,
     
-    /// Current SAS attestation PDA (from Civic or other trusted issuer)
-    /// This is the on-chain proof of identity verification
-    pub attestation: Pubkey,
+    /// Recovery config
+    #[account(
+        mut,
+        seeds = [b"recovery", user_state.key().as_ref()],
+        bump = recovery_config.bump
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
     
-    /// When the attestation was last updated
-    /// Allows tracking attestation history
-    pub attestation_updated_at: i64,
+    /// UserState PDA of the new wallet (must not exist yet)
+    /// CHECK: Only checked to be empty
+    #[account(
+        seeds = [b"user", new_owner.as_ref()],
+        bump
+    )]
+    pub new_user_state: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    /// Guardian approving
+    pub guardian: Signer<'info>,
     
-    /// Number of times attestation has been updated
-    /// Useful for detecting unusual patterns
-    pub attestation_update_count: u8,
+    /// User state being recovered
+    #[account(
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump
+    )]
+    pub user_state: Account<'info, UserState>,
     
-    /// PDA bump seed
-    pub bump: u8,
+    /// Recovery config
+    #[account(
+        mut,
+        seeds = [b"recovery", user_state.key().as_ref()],
+        bump = recovery_config.bump
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
 }
 
-impl UserState {
-    pub const SIZE: usize = 8 +  // Discriminator
-        32 +    // owner
-        8 +     // registration_timestamp
-        8 +     // last_claim_timestamp
-        8 +     // age_in_days_at_registration
-        3 +     // citizenship
-        1 +     // is_verified
-        1 +     // is_child
-        16 +    // total_claimed
-        8 +     // locked_balance
-        8 +     // transaction_count
-        8 +     // last_transaction_timestamp
-        8 +     // liveness_verified_at
-        8 +     // liveness_expires_at
-        32 +    // attestation (SAS PDA)
-        8 +     // attestation_updated_at
-        1 +     // attestation_update_count
-        1;      // bump
+#[derive(Accounts)]
+pub struct VetoRecovery<'info> {
+    /// Current wallet
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// Recovery config
+    #[account(
+        mut,
+        seeds = [b"recovery", user_state.key().as_ref()],
+        bump = recovery_config.bump
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+}
+
+#[derive(Accounts)]
+pub struct CompleteRecovery<'info> {
+    /// New wallet
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+    
+    /// Old user state (closed, rent to new wallet)
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [b"user", old_user_state.owner.as_ref()],
+        bump = old_user_state.bump
+    )]
+    pub old_user_state: Account<'info, UserState>,
+    
+    /// Recovery config (closed)
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [b"recovery", old_user_state.key().as_ref()],
+        bump = recovery_config.bump,
+        constraint = recovery_config.pending_new_owner == new_owner.key() @ CoreError::Unauthorized
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+    
+    /// New user state PDA
+    #[account(
+        init,
+        payer = new_owner,
+        space = UserState::SIZE,
+        seeds = [b"user", new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_user_state: Account<'info, UserState>,
+    
+    /// Biometric record (re-linked to the new wallet)
+    #[account(
+        mut,
+        seeds = [b"biometric", biometric_record.hash.as_ref()],
+        bump = biometric_record.bump,
+        constraint = biometric_record.user == old_user_state.owner @ CoreError::Unauthorized,
+        constraint = biometric_record.is_registered @ CoreError::Unauthorized
+    )]
+    pub biometric_record: Account<'info, BiometricRecord>,
+    
+    /// Pending wallet rotation of the old user state (closed if it exists)
+    /// CHECK: PDA verified, closed only if owned by this program
+    #[account(
+        mut,
+        seeds = [b"rotation", old_user_state.key().as_ref()],
+        bump
+    )]
+    pub wallet_rotation: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 // ============================================================================
-// BIOMETRIC REGISTRY
+// FRAUD CHALLENGE CONTEXTS
 // ============================================================================
 
-/// Global registry tracking total biometric registrations.
-/// 
-/// This is a singleton PDA that tracks statistics only.
-/// Individual biometric hashes are stored in BiometricRecord PDAs.
-#[account]
-pub struct BiometricRegistry {
-    /// Total number of registered biometrics
-    pub total_registered: u64,
+#[derive(Accounts)]
+pub struct InitializeChallengePool<'info> {
+    /// Anyone (pays for account creation)
+    #[account(mut)]
+    pub payer: Signer<'info>,
     
-    /// PDA bump seed
-    pub bump: u8,
+    /// Protocol state (vault authority)
+    #[account(
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    /// Token mint
+    #[account(address = protocol_state.mint)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Challenge pool PDA
+    #[account(
+        init,
+        payer = payer,
+        space = ChallengePool::SIZE,
+        seeds = [b"challenge_pool"],
+        bump
+    )]
+    pub challenge_pool: Account<'info, ChallengePool>,
+    
+    /// Bond vault (owned by protocol state)
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"challenge_vault"],
+        bump,
+        token::mint = mint,
+        token::authority = protocol_state,
+    )]
+    pub challenge_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
-impl BiometricRegistry {
-    pub const SIZE: usize = 8 +  // Discriminator
-        8 +     // total_registered
-        1;      // bump
+#[derive(Accounts)]
+pub struct OpenFraudChallenge<'info> {
+    /// Challenger (pays bond and rent)
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+    
+    /// Protocol state
+    #[account(
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    /// Challenger's AGORA account
+    #[account(
+        mut,
+        token::mint = protocol_state.mint,
+        token::authority = challenger,
+    )]
+    pub challenger_token_account: Account<'info, TokenAccount>,
+    
+    /// First accused account
+    #[account(
+        seeds = [b"user", user_a.owner.as_ref()],
+        bump = user_a.bump
+    )]
+    pub user_a: Account<'info, UserState>,
+    
+    /// Second accused account
+    #[account(
+        seeds = [b"user", user_b.owner.as_ref()],
+        bump = user_b.bump
+    )]
+    pub user_b: Account<'info, UserState>,
+    
+    /// Challenge PDA (one per pair)
+    #[account(
+        init,
+        payer = challenger,
+        space = FraudChallenge::SIZE,
+        seeds = [b"fraud_challenge", user_a.key().as_ref(), user_b.key().as_ref()],
+        bump
+    )]
+    pub fraud_challenge: Account<'info, FraudChallenge>,
+    
+    /// Challenge pool
+    #[account(
+        mut,
+        seeds = [b"challenge_pool"],
+        bump = challenge_pool.bump
+    )]
+    pub challenge_pool: Account<'info, ChallengePool>,
+    
+    /// Bond vault
+    #[account(
+        mut,
+        seeds = [b"challenge_vault"],
+        bump
+    )]
+    pub challenge_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
-/// Individual biometric record.
-/// 
-/// PDA derived from biometric hash - ensures uniqueness.
-/// If a record exists for a hash, that human is already registered.
-#[account]
-pub struct BiometricRecord {
-    /// The biometric hash (SHA-256 of fingerprint, iris, DNA, etc.)
-    pub hash: [u8; 32],
+#[derive(Accounts)]
+pub struct ResolveFraudChallenge<'info> {
+    /// Trusted issuer authority
+    pub resolver: Signer<'info>,
     
-    /// The user this biometric belongs to
-    pub user: Pubkey,
+    /// Trusted issuer account from Governance program
+    /// CHECK: Verified manually - active issuer whose authority is the resolver
+    #[account(
+        seeds = [b"trusted_issuer", trusted_issuer_credential.key().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub trusted_issuer: AccountInfo<'info>,
     
-    /// When this biometric was registered
-    pub registered_at: i64,
+    /// The credential pubkey used to derive trusted_issuer PDA
+    /// CHECK: Used only for PDA derivation
+    pub trusted_issuer_credential: AccountInfo<'info>,
     
-    /// Whether this is a valid registration (false if superseded)
-    pub is_registered: bool,
+    /// Protocol state (vault authority)
+    #[account(
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
     
-    /// When this record was invalidated (0 if still active)
-    pub invalidated_at: i64,
+    /// Challenge being resolved (closed, rent to the challenger)
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [b"fraud_challenge", user_a.key().as_ref(), user_b.key().as_ref()],
+        bump = fraud_challenge.bump
+    )]
+    pub fraud_challenge: Account<'info, FraudChallenge>,
     
-    /// Previous biometric hash (if this is an update)
-    pub previous_hash: Option<[u8; 32]>,
+    /// Challenger wallet (receives the challenge rent)
+    /// CHECK: Must match fraud_challenge.challenger
+    #[account(mut, address = fraud_challenge.challenger @ CoreError::Unauthorized)]
+    pub challenger: AccountInfo<'info>,
+    
+    /// First accused account
+    #[account(mut, address = fraud_challenge.user_a)]
+    pub user_a: Account<'info, UserState>,
+    
+    /// Second accused account
+    #[account(mut, address = fraud_challenge.user_b)]
+    pub user_b: Account<'info, UserState>,
+    
+    /// Challenge pool
+    #[account(
+        mut,
+        seeds = [b"challenge_pool"],
+        bump = challenge_pool.bump
+    )]
+    pub challenge_pool: Account<'info, ChallengePool>,
+    
+    /// Bond vault
+    #[account(
+        mut,
+        seeds = [b"challenge_vault"],
+        bump
+    )]
+    pub challenge_vault: Account<'info, TokenAccount>,
+    
+    /// Challenger's AGORA account (receives payout if upheld)
+    #[account(
+        mut,
+        token::mint = protocol_state.mint,
+        token::authority = fraud_challenge.challenger,
+    )]
+    pub challenger_token_account: Account<'info, TokenAccount>,
     
-    /// PDA bump seed
-    pub bump: u8,
+    pub token_program: Program<'info, Token>,
 }
 
-impl BiometricRecord {
-    pub const SIZE: usize = 8 +  // Discriminator
-        32 +    // hash
-        32 +    // user
-        8 +     // registered_at
-        1 +     // is_registered
-        8 +     // invalidated_at
-        1 + 32 + // previous_hash (Option)
-        1;      // bump
+// ============================================================================
+// SUSPENSION CONTEXTS
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ApplyUserOrder<'info> {
+    /// User state the order applies to
+    #[account(
+        mut,
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump
+    )]
+    pub user_state: Account<'info, UserState>,
+    
+    /// UserOrder from Governance program
+    /// CHECK: PDA verified against Governance program, parsed manually
+    #[account(
+        seeds = [b"user_order", proposal_id.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub user_order: AccountInfo<'info>,
 }
 
-// ╔═══════════════════════════════════════════════════════════════════════════╗
-// ║                                                                           ║
-// ║                          CONTEXT STRUCTURES                               ║
-// ║                                                                           ║
-// ╚═══════════════════════════════════════════════════════════════════════════╝
+#[derive(Accounts)]
+pub struct AppealSuspension<'info> {
+    /// Suspended user
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
+}
 
 // ============================================================================
-// INITIALIZE CONTEXT
+// DORMANT ACCOUNT CONTEXTS
 // ============================================================================
 
 #[derive(Accounts)]
-pub struct Initialize<'info> {
-    /// Deployer (pays for account creation)
-    #[account(mut)]
-    pub authority: Signer<'info>,
+pub struct SetHeir<'info> {
+    /// User choosing the heir
+    pub owner: Signer<'info>,
+    
+    /// User state
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub user_state: Account<'info, UserState>,
     
-    /// Protocol state PDA
     #[account(
-        init,
-        payer = authority,
-        space = ProtocolState::SIZE,
         seeds = [b"protocol"],
-        bump
+        bump = protocol_state.bump
     )]
     pub protocol_state: Account<'info, ProtocolState>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveDormantTransfer<'info> {
+    /// User opting in
+    pub owner: Signer<'info>,
     
-    /// Biometric registry PDA
+    /// Only registered users can opt in
     #[account(
-        init,
-        payer = authority,
-        space = BiometricRegistry::SIZE,
-        seeds = [b"biometric_registry"],
-        bump
+        seeds = [b"user", owner.key().as_ref()],
+        bump = user_state.bump,
+        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
     )]
-    pub biometric_registry: Account<'info, BiometricRegistry>,
+    pub user_state: Account<'info, UserState>,
     
-    /// AGORA token mint
+    /// Protocol state (approved as delegate)
     #[account(
-        init,
-        payer = authority,
-        mint::decimals = TOKEN_DECIMALS,
-        mint::authority = protocol_state,
+        seeds = [b"protocol"],
+        bump = protocol_state.bump
     )]
-    pub mint: Account<'info, Mint>,
+    pub protocol_state: Account<'info, ProtocolState>,
     
-    /// Treasury token account
-    /// CHECK: Created by associated token program
-    #[account(mut)]
-    pub treasury: AccountInfo<'info>,
+    /// User's token account
+    #[account(
+        mut,
+        associated_token::mint = protocol_state.mint,
+        associated_token::authority = owner,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
     
     pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
-// ============================================================================
-// REGISTER USER CONTEXT
-// ============================================================================
-
 #[derive(Accounts)]
-#[instruction(age_in_days: u64, biometric_hash: [u8; 32], citizenship: [u8; 3])]
-pub struct RegisterUser<'info> {
-    /// User registering
-    #[account(mut)]
-    pub owner: Signer<'info>,
+pub struct MarkDormant<'info> {
+    /// Anyone can mark an expired account
+    pub caller: Signer<'info>,
     
-    /// User state PDA
+    /// User state
     #[account(
-        init,
-        payer = owner,
-        space = UserState::SIZE,
-        seeds = [b"user", owner.key().as_ref()],
-        bump
+        mut,
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump
     )]
     pub user_state: Account<'info, UserState>,
+}
+
+#[derive(Accounts)]
+pub struct CloseDormantAccount<'info> {
+    /// Anyone can close an account past the closure period
+    pub caller: Signer<'info>,
     
-    /// Biometric record PDA (derived from hash)
+    /// Dormant user state (rent goes to the beneficiary)
     #[account(
-        init,
-        payer = owner,
-        space = BiometricRecord::SIZE,
-        seeds = [b"biometric", biometric_hash.as_ref()],
-        bump
+        mut,
+        seeds = [b"user", user_state.owner.as_ref()],
+        bump = user_state.bump,
+        close = beneficiary
     )]
-    pub biometric_record: Account<'info, BiometricRecord>,
+    pub user_state: Account<'info, UserState>,
     
-    /// Biometric registry (to update count)
+    /// User's biometric record (kept, marked retired)
     #[account(
         mut,
-        seeds = [b"biometric_registry"],
-        bump = biometric_registry.bump
+        seeds = [b"biometric", biometric_record.hash.as_ref()],
+        bump = biometric_record.bump,
+        constraint = biometric_record.user == user_state.owner @ CoreError::Unauthorized
     )]
-    pub biometric_registry: Account<'info, BiometricRegistry>,
+    pub biometric_record: Account<'info, BiometricRecord>,
     
-    /// Protocol state
     #[account(
         mut,
         seeds = [b"protocol"],
@@ -1400,281 +4916,1015 @@ pub struct RegisterUser<'info> {
     )]
     pub protocol_state: Account<'info, ProtocolState>,
     
-    /// Token mint
+    /// Governance CoreParameters (closure period)
+    /// CHECK: PDA verified against Governance program, parsed manually
+    #[account(
+        seeds = [b"core_parameters"],
+        bump,
+        seeds::program = GOVERNANCE_PROGRAM_ID
+    )]
+    pub core_parameters: AccountInfo<'info>,
+    
+    /// Heir wallet, or the treasury if no heir was set
+    /// CHECK: Must match the user's heir (or treasury)
     #[account(
         mut,
-        address = protocol_state.mint
+        constraint = beneficiary.key() == dormant_beneficiary(&user_state, &protocol_state)
+            @ CoreError::InvalidHeir
     )]
-    pub mint: Account<'info, Mint>,
+    pub beneficiary: AccountInfo<'info>,
     
-    /// User's token account
+    /// Beneficiary's token account
     #[account(
-        init_if_needed,
-        payer = owner,
-        associated_token::mint = mint,
-        associated_token::authority = owner,
+        mut,
+        token::mint = protocol_state.mint,
+        token::authority = beneficiary,
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
     
-    /// Optional: Country sanction account from Governance program
-    /// If this account exists and is active, UBI is reduced
-    /// CHECK: We verify this is from Governance program and parse manually
+    /// User's token account (always the owner's ATA; moved only if it
+    /// exists and the protocol is its delegate)
+    /// CHECK: Address verified; parsed only if owned by the token program
     #[account(
-        seeds = [b"sanction", citizenship.as_ref()],
-        bump,
-        seeds::program = GOVERNANCE_PROGRAM_ID,
+        mut,
+        address = get_associated_token_address(&user_state.owner, &protocol_state.mint)
+            @ CoreError::InvalidTokenAccount
     )]
-    pub country_sanction: Option<AccountInfo<'info>>,
+    pub user_token_account: AccountInfo<'info>,
     
-    /// SAS Attestation from Civic (or other trusted issuer)
-    /// This proves the user has passed identity verification
-    /// CHECK: Verified manually - must be valid, not expired, from trusted issuer
-    pub attestation: AccountInfo<'info>,
+    /// Child escrow (required if the user has one)
+    #[account(
+        mut,
+        address = user_state.child_escrow @ CoreError::ChildEscrowRequired
+    )]
+    pub child_escrow: Option<Account<'info, TokenAccount>>,
+    
+    /// Recovery config of the user state (closed if it exists)
+    /// CHECK: PDA verified, closed only if owned by this program
+    #[account(
+        mut,
+        seeds = [b"recovery", user_state.key().as_ref()],
+        bump
+    )]
+    pub recovery_config: AccountInfo<'info>,
+    
+    /// Pending wallet rotation of the user state (closed if it exists)
+    /// CHECK: PDA verified, closed only if owned by this program
+    #[account(
+        mut,
+        seeds = [b"rotation", user_state.key().as_ref()],
+        bump
+    )]
+    pub wallet_rotation: AccountInfo<'info>,
     
     pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
+// ╔═══════════════════════════════════════════════════════════════════════════╗
+// ║                                                                           ║
+// ║                           HELPER FUNCTIONS                                ║
+// ║                                                                           ║
+// ╚═══════════════════════════════════════════════════════════════════════════╝
+
 // ============================================================================
-// CLAIM DAILY CONTEXT
+// SANCTION PARSING
 // ============================================================================
+// CountrySanction layout (Governance program):
+// - 8 bytes:  discriminator
+// - 3 bytes:  country_code     @ 8
+// - 1 byte:   ubi_percentage   @ 11
+// - 8 bytes:  imposed_at       @ 12
+// - 8 bytes:  expires_at       @ 20
+// - 8 bytes:  proposal_id      @ 28
+// - 1 byte:   is_active        @ 36
+// - 1 byte:   lifted_early     @ 37
+// - 8 bytes:  lifted_at        @ 38
+// - 8 bytes:  terms_start      @ 46
+// - 4 bytes:  renewal_count    @ 54
+// - 4 x 9:    schedule         @ 58 (starts_after: i64, ubi_percentage: u8)
 
-#[derive(Accounts)]
-pub struct ClaimDaily<'info> {
-    /// User claiming
-    pub owner: Signer<'info>,
+const SANCTION_UBI_PCT_OFFSET: usize = 11;
+const SANCTION_EXPIRES_AT_OFFSET: usize = 20;
+const SANCTION_IS_ACTIVE_OFFSET: usize = 36;
+const SANCTION_TERMS_START_OFFSET: usize = 46;
+const SANCTION_SCHEDULE_OFFSET: usize = 58;
+const SANCTION_STEP_SIZE: usize = 9;
+
+/// Effective UBI percentage for a Governance CountrySanction account.
+/// 
+/// Returns 100 (full UBI) if the account is not owned by Governance,
+/// is not initialized, has been lifted or has expired. Otherwise the
+/// base percentage applies, replaced by the latest schedule step whose
+/// start time has passed. Never returns more than 100.
+fn read_sanction_percentage(sanction_account: &AccountInfo, current_time: i64) -> Result<u64> {
+    if *sanction_account.owner != GOVERNANCE_PROGRAM_ID {
+        return Ok(100);
+    }
     
-    /// User state
-    #[account(
-        mut,
-        seeds = [b"user", owner.key().as_ref()],
-        bump = user_state.bump,
-        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
-    )]
-    pub user_state: Account<'info, UserState>,
+    let data = sanction_account.try_borrow_data()?;
+    let schedule_end = SANCTION_SCHEDULE_OFFSET + SANCTION_STEP_SIZE * SANCTION_SCHEDULE_STEPS;
+    if data.len() < schedule_end {
+        return Ok(100); // Invalid account = full UBI
+    }
+    
+    let read_i64 = |offset: usize| -> i64 {
+        i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+    };
+    
+    let is_active = data[SANCTION_IS_ACTIVE_OFFSET] == 1;
+    let expires_at = read_i64(SANCTION_EXPIRES_AT_OFFSET);
+    if !is_active || current_time >= expires_at {
+        return Ok(100); // Lifted or expired = full UBI
+    }
+    
+    let terms_start = read_i64(SANCTION_TERMS_START_OFFSET);
+    let mut ubi_pct = data[SANCTION_UBI_PCT_OFFSET];
+    
+    for i in 0..SANCTION_SCHEDULE_STEPS {
+        let step = SANCTION_SCHEDULE_OFFSET + i * SANCTION_STEP_SIZE;
+        let step_pct = data[step + 8];
+        if step_pct == 0 {
+            break; // Unused steps are zeroed
+        }
+        if current_time >= terms_start.saturating_add(read_i64(step)) {
+            ubi_pct = step_pct;
+        }
+    }
+    
+    Ok((ubi_pct as u64).min(100))
+}
+
+/// Effective UBI percentage across all of a user's citizenships.
+/// 
+/// `primary` is the sanction PDA for `user.citizenship`. The sanction PDAs
+/// for additional citizenships must follow in `extra`, in the same order
+/// as `user.additional_citizenships`. The most favourable (highest)
+/// percentage applies.
+fn effective_sanction_percentage(
+    user: &UserState,
+    primary: &AccountInfo,
+    extra: &[AccountInfo],
+    current_time: i64,
+) -> Result<u64> {
+    let mut percentage = read_sanction_percentage(primary, current_time)?;
+    
+    let count = user.additional_citizenship_count as usize;
+    require!(extra.len() >= count, CoreError::MissingSanctionAccount);
+    
+    for (country, account) in user.additional_citizenships[..count].iter().zip(extra) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"sanction".as_ref(), country.as_ref()],
+            &GOVERNANCE_PROGRAM_ID,
+        );
+        require_keys_eq!(account.key(), expected, CoreError::MissingSanctionAccount);
+        percentage = percentage.max(read_sanction_percentage(account, current_time)?);
+    }
+    
+    Ok(percentage)
+}
+
+// ============================================================================
+// ATTESTATION VALIDATION
+// ============================================================================
+// SAS Attestation structure (from SAS program):
+// - 8 bytes: discriminator
+// - 32 bytes: credential (issuer)  @ 8
+// - 32 bytes: schema               @ 40
+// - 32 bytes: nonce (wallet)       @ 72
+// - 8 bytes: expiry timestamp      @ 104
+// - 1 byte: is_revoked             @ 112
+// - variable: data                 @ 113
+//
+// The offsets below are shared with Governance (⚫ IMMUTABLE).
+// TrustedIssuer structure (Governance program):
+// - 8 bytes: discriminator
+// - 32 bytes: credential           @ 8
+// - 32 bytes: name                 @ 40
+// - 8 bytes: approved_at           @ 72
+// - 8 bytes: proposal_id           @ 80
+// - 1 byte: is_active              @ 88
+// - 8 bytes: deactivated_at        @ 89
+// - 8 bytes: deactivation_prop_id  @ 97
+// - 8 bytes: total_attestations    @ 105
+// - 32 bytes: authority            @ 113
+// - 8 bytes: migration_deadline    @ 145
+
+pub const SAS_CREDENTIAL_OFFSET: usize = 8;
+pub const SAS_SCHEMA_OFFSET: usize = 40;
+pub const SAS_NONCE_OFFSET: usize = 72;
+pub const SAS_EXPIRY_OFFSET: usize = 104;
+pub const SAS_REVOKED_OFFSET: usize = 112;
+pub const SAS_DATA_OFFSET: usize = 113;
+
+/// Offset of the attested ISO 3166-1 alpha-3 country code
+/// (first bytes of the attestation data for citizenship schemas)
+const ATTESTATION_COUNTRY_OFFSET: usize = SAS_DATA_OFFSET;
+
+/// Offset of the attested biometric hash
+/// (first bytes of the attestation data for identity schemas)
+const ATTESTATION_BIOMETRIC_OFFSET: usize = SAS_DATA_OFFSET;
+
+/// Offset of `migration_deadline` in a Governance TrustedIssuer account
+const ISSUER_MIGRATION_DEADLINE_OFFSET: usize = 145;
+
+/// Verify a SAS attestation for `wallet` against a Governance trusted issuer.
+/// 
+/// Checks: owned by SAS, issued by an active trusted issuer, linked to the
+/// wallet, not expired, not revoked. Used whenever a NEW attestation is
+/// presented.
+fn validate_attestation(
+    attestation: &AccountInfo,
+    trusted_issuer: &AccountInfo,
+    wallet: &Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    check_attestation(attestation, trusted_issuer, wallet, current_time, false)?;
+    Ok(())
+}
+
+/// Verify the attestation a user already holds (claims, lapse checks).
+/// 
+/// Same checks as `validate_attestation`, except that an issuer removed by
+/// Governance is still accepted until its migration deadline. Returns that
+/// deadline (0 if the issuer is active).
+fn validate_existing_attestation(
+    attestation: &AccountInfo,
+    trusted_issuer: &AccountInfo,
+    wallet: &Pubkey,
+    current_time: i64,
+) -> Result<i64> {
+    check_attestation(attestation, trusted_issuer, wallet, current_time, true)
+}
+
+fn check_attestation(
+    attestation: &AccountInfo,
+    trusted_issuer: &AccountInfo,
+    wallet: &Pubkey,
+    current_time: i64,
+    allow_migration_grace: bool,
+) -> Result<i64> {
+    require_keys_eq!(*attestation.owner, SAS_PROGRAM_ID, CoreError::InvalidAttestation);
+    require_keys_eq!(*trusted_issuer.owner, GOVERNANCE_PROGRAM_ID, CoreError::UntrustedIssuer);
+    
+    let attestation_data = attestation.try_borrow_data()?;
+    require!(
+        attestation_data.len() >= SAS_DATA_OFFSET,
+        CoreError::InvalidAttestation
+    );
+    
+    let credential = Pubkey::new_from_array(
+        attestation_data[SAS_CREDENTIAL_OFFSET..SAS_CREDENTIAL_OFFSET + 32].try_into().unwrap()
+    );
+    let nonce = Pubkey::new_from_array(
+        attestation_data[SAS_NONCE_OFFSET..SAS_NONCE_OFFSET + 32].try_into().unwrap()
+    );
+    let expiry = i64::from_le_bytes(
+        attestation_data[SAS_EXPIRY_OFFSET..SAS_EXPIRY_OFFSET + 8].try_into().unwrap()
+    );
+    let is_revoked = attestation_data[SAS_REVOKED_OFFSET] == 1;
+    
+    let issuer_data = trusted_issuer.try_borrow_data()?;
+    require!(
+        issuer_data.len() >= ISSUER_MIGRATION_DEADLINE_OFFSET + 8,
+        CoreError::InvalidAttestation
+    );
+    
+    let issuer_credential = Pubkey::new_from_array(issuer_data[8..40].try_into().unwrap());
+    let issuer_is_active = issuer_data[88] == 1;
+    
+    // Check 1: Attestation must be from an active trusted issuer
+    // (or a removed one still inside its migration window)
+    require!(credential == issuer_credential, CoreError::UntrustedIssuer);
+    let mut migration_deadline = 0;
+    if !issuer_is_active {
+        migration_deadline = i64::from_le_bytes(
+            issuer_data[ISSUER_MIGRATION_DEADLINE_OFFSET..ISSUER_MIGRATION_DEADLINE_OFFSET + 8]
+                .try_into()
+                .unwrap()
+        );
+        require!(
+            allow_migration_grace && current_time < migration_deadline,
+            CoreError::UntrustedIssuer
+        );
+    }
+    
+    // Check 2: Attestation must be linked to this wallet
+    require!(nonce == *wallet, CoreError::AttestationWalletMismatch);
+    
+    // Check 3: Attestation must not be expired
+    require!(expiry == 0 || expiry > current_time, CoreError::AttestationExpired);
+    
+    // Check 4: Attestation must not be revoked
+    require!(!is_revoked, CoreError::AttestationRevoked);
+    
+    Ok(migration_deadline)
+}
+
+/// Read the issuer credential of a SAS attestation (default if unreadable).
+fn read_attestation_credential(attestation: &AccountInfo) -> Pubkey {
+    match attestation.try_borrow_data() {
+        Ok(data) if data.len() >= SAS_CREDENTIAL_OFFSET + 32 => Pubkey::new_from_array(
+            data[SAS_CREDENTIAL_OFFSET..SAS_CREDENTIAL_OFFSET + 32].try_into().unwrap()
+        ),
+        _ => Pubkey::default(),
+    }
+}
+
+/// Require a SAS attestation to use its issuer's `schema_name` schema.
+/// 
+/// SAS schema PDA: ["schema", credential, name, version]
+fn require_attestation_schema(attestation: &AccountInfo, schema_name: &[u8]) -> Result<()> {
+    let data = attestation.try_borrow_data()?;
+    require!(data.len() >= SAS_SCHEMA_OFFSET + 32, CoreError::InvalidAttestation);
+    
+    let (expected_schema, _) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &data[SAS_CREDENTIAL_OFFSET..SAS_CREDENTIAL_OFFSET + 32],
+            schema_name,
+            &[ATTESTATION_SCHEMA_VERSION],
+        ],
+        &SAS_PROGRAM_ID,
+    );
+    require!(
+        data[SAS_SCHEMA_OFFSET..SAS_SCHEMA_OFFSET + 32] == expected_schema.to_bytes(),
+        CoreError::WrongAttestationSchema
+    );
+    
+    Ok(())
+}
+
+/// Read the country code carried by a citizenship attestation.
+fn read_attested_country(attestation: &AccountInfo) -> Result<[u8; 3]> {
+    require_attestation_schema(attestation, CITIZENSHIP_SCHEMA_NAME)?;
     
-    /// Protocol state
-    #[account(
-        mut,
-        seeds = [b"protocol"],
-        bump = protocol_state.bump
-    )]
-    pub protocol_state: Account<'info, ProtocolState>,
+    let data = attestation.try_borrow_data()?;
+    require!(
+        data.len() >= ATTESTATION_COUNTRY_OFFSET + 3,
+        CoreError::InvalidAttestation
+    );
+    Ok(data[ATTESTATION_COUNTRY_OFFSET..ATTESTATION_COUNTRY_OFFSET + 3].try_into().unwrap())
+}
+
+/// Read the biometric hash carried by an identity attestation.
+fn read_attested_biometric(attestation: &AccountInfo) -> Result<[u8; 32]> {
+    require_attestation_schema(attestation, IDENTITY_SCHEMA_NAME)?;
     
-    /// Token mint
-    #[account(
-        mut,
-        address = protocol_state.mint
-    )]
-    pub mint: Account<'info, Mint>,
+    let data = attestation.try_borrow_data()?;
+    require!(
+        data.len() >= ATTESTATION_BIOMETRIC_OFFSET + 32,
+        CoreError::InvalidAttestation
+    );
+    Ok(data[ATTESTATION_BIOMETRIC_OFFSET..ATTESTATION_BIOMETRIC_OFFSET + 32].try_into().unwrap())
+}
+
+/// Offset of `authority` in a Governance TrustedIssuer account
+const ISSUER_AUTHORITY_OFFSET: usize = 113;
+
+/// Require `signer` to be the authority of an active trusted issuer.
+fn require_issuer_authority(trusted_issuer: &AccountInfo, signer: &Pubkey) -> Result<()> {
+    require_keys_eq!(*trusted_issuer.owner, GOVERNANCE_PROGRAM_ID, CoreError::UntrustedIssuer);
     
-    /// User's token account
-    #[account(
-        mut,
-        associated_token::mint = mint,
-        associated_token::authority = owner,
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    let issuer_data = trusted_issuer.try_borrow_data()?;
+    require!(
+        issuer_data.len() >= ISSUER_AUTHORITY_OFFSET + 32,
+        CoreError::UntrustedIssuer
+    );
+    require!(issuer_data[88] == 1, CoreError::UntrustedIssuer);
     
-    /// Optional: Country sanction account
-    /// CHECK: Verified and parsed manually
-    pub country_sanction: Option<AccountInfo<'info>>,
+    let authority = Pubkey::new_from_array(
+        issuer_data[ISSUER_AUTHORITY_OFFSET..ISSUER_AUTHORITY_OFFSET + 32].try_into().unwrap()
+    );
+    require_keys_eq!(authority, *signer, CoreError::Unauthorized);
     
-    pub token_program: Program<'info, Token>,
+    Ok(())
 }
 
 // ============================================================================
-// VERIFY LIVENESS CONTEXT
+// USER STATE MIGRATION
 // ============================================================================
 
-#[derive(Accounts)]
-pub struct VerifyLiveness<'info> {
-    /// User verifying liveness
-    pub owner: Signer<'info>,
+/// Copy a UserState for a new wallet (wallet rotation and recovery).
+/// 
+/// Everything tied to the human - claim history, liveness, citizenships,
+/// locked balance - is kept. Owner, attestation and bump change.
+fn migrate_user_state(
+    old: &UserState,
+    old_key: Pubkey,
+    new_owner: Pubkey,
+    new_attestation: Pubkey,
+    new_attestation_issuer: Pubkey,
+    bump: u8,
+    current_time: i64,
+) -> UserState {
+    let mut migrated = old.clone();
+    migrated.owner = new_owner;
+    if new_attestation != old.attestation {
+        migrated.attestation = new_attestation;
+        migrated.attestation_issuer = new_attestation_issuer;
+        migrated.attestation_lapsed_at = 0;
+        migrated.attestation_updated_at = current_time;
+        migrated.attestation_update_count = old.attestation_update_count.saturating_add(1);
+    }
+    // The delegate was authorised by the old wallet
+    migrated.claim_delegate = Pubkey::default();
+    migrated.previous_user_state = old_key;
+    migrated.last_transaction_timestamp = current_time;
+    migrated.bump = bump;
+    migrated
+}
+
+/// Close a PDA of this program if it exists (rent to `destination`).
+/// 
+/// Used for per-UserState accounts left behind when a UserState moves.
+fn close_if_exists<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    if *account.owner == crate::ID && !account.data_is_empty() {
+        let lamports = account.lamports();
+        **account.try_borrow_mut_lamports()? = 0;
+        **destination.try_borrow_mut_lamports()? += lamports;
+        account.assign(&System::id());
+        account.realloc(0, false)?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// SOCIAL RECOVERY
+// ============================================================================
+
+/// Validate and store a guardian set.
+/// 
+/// `guardian_states` must hold the UserState PDA of each guardian, in order.
+fn set_recovery_guardians(
+    config: &mut RecoveryConfig,
+    owner: &Pubkey,
+    guardians: &[Pubkey],
+    threshold: u8,
+    guardian_states: &[AccountInfo],
+) -> Result<()> {
+    let count = guardians.len();
+    require!(
+        (MIN_RECOVERY_GUARDIANS..=MAX_RECOVERY_GUARDIANS).contains(&count),
+        CoreError::InvalidRecoveryGuardians
+    );
+    require!(
+        threshold as usize <= count && threshold as usize * 2 > count,
+        CoreError::InvalidRecoveryGuardians
+    );
+    require!(guardian_states.len() >= count, CoreError::InvalidRecoveryGuardians);
     
-    /// User state
-    #[account(
-        mut,
-        seeds = [b"user", owner.key().as_ref()],
-        bump = user_state.bump,
-        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
-    )]
-    pub user_state: Account<'info, UserState>,
+    for (i, (guardian, state_info)) in guardians.iter().zip(guardian_states).enumerate() {
+        require!(
+            guardian != owner && !guardians[..i].contains(guardian),
+            CoreError::InvalidRecoveryGuardians
+        );
+        
+        // Guardian must be a verified human registered in Core
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"user".as_ref(), guardian.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(state_info.key(), expected, CoreError::InvalidRecoveryGuardians);
+        require_keys_eq!(*state_info.owner, crate::ID, CoreError::InvalidRecoveryGuardians);
+        
+        let data = state_info.try_borrow_data()?;
+        let guardian_state = UserState::try_deserialize(&mut &data[..])?;
+        require!(guardian_state.is_verified, CoreError::InvalidRecoveryGuardians);
+    }
     
-    /// Biometric record (to verify hash matches)
-    #[account(
-        seeds = [b"biometric", biometric_record.hash.as_ref()],
-        bump = biometric_record.bump,
-        constraint = biometric_record.user == owner.key() @ CoreError::Unauthorized
-    )]
-    pub biometric_record: Account<'info, BiometricRecord>,
+    config.guardians = [Pubkey::default(); MAX_RECOVERY_GUARDIANS];
+    config.guardians[..count].copy_from_slice(guardians);
+    config.guardian_count = count as u8;
+    config.threshold = threshold;
+    config.pending_new_owner = Pubkey::default();
+    config.approvals = 0;
+    config.proposed_at = 0;
+    config.challenge_ends_at = 0;
+    
+    Ok(())
+}
+
+/// Start the challenge period once enough guardians approved.
+fn record_recovery_threshold(config: &mut RecoveryConfig, user: Pubkey, current_time: i64) {
+    if config.challenge_ends_at == 0 && config.approvals.count_ones() as u8 >= config.threshold {
+        config.challenge_ends_at = current_time + RECOVERY_CHALLENGE_PERIOD;
+        
+        emit!(RecoveryChallengeStarted {
+            user,
+            new_owner: config.pending_new_owner,
+            challenge_ends_at: config.challenge_ends_at,
+            timestamp: current_time,
+        });
+        
+        msg!("Recovery threshold reached. Completable after {}", config.challenge_ends_at);
+    }
 }
 
 // ============================================================================
-// UPDATE BIOMETRIC CONTEXT
+// SUSPENSIONS
 // ============================================================================
+// UserOrder layout (Governance program):
+// - 8 bytes: discriminator
+// - 8 bytes: proposal_id       @ 8
+// - 32 bytes: target_account   @ 16
+// - 1 byte: order_type         @ 48 (0 = suspend, 1 = reinstate)
+// - 1 byte: reason_code        @ 49
 
-#[derive(Accounts)]
-#[instruction(old_biometric_proof: [u8; 32], new_biometric_hash: [u8; 32])]
-pub struct UpdateBiometric<'info> {
-    /// User updating their biometric
-    #[account(mut)]
-    pub owner: Signer<'info>,
-    
-    /// User state
-    #[account(
-        mut,
-        seeds = [b"user", owner.key().as_ref()],
-        bump = user_state.bump,
-        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
-    )]
-    pub user_state: Account<'info, UserState>,
+const USER_ORDER_SUSPEND: u8 = 0;
+const USER_ORDER_REINSTATE: u8 = 1;
+
+/// Validate a Governance UserOrder for `user` and return its reason code.
+/// 
+/// Each order is applied once: its proposal must be newer than the last
+/// order applied to the account.
+fn read_user_order(
+    order: &AccountInfo,
+    user: &Account<UserState>,
+    proposal_id: u64,
+    expected_type: u8,
+) -> Result<u8> {
+    require_keys_eq!(*order.owner, GOVERNANCE_PROGRAM_ID, CoreError::InvalidUserOrder);
     
-    /// Old biometric record (to be invalidated)
-    #[account(
-        mut,
-        seeds = [b"biometric", old_biometric_record.hash.as_ref()],
-        bump = old_biometric_record.bump,
-        constraint = old_biometric_record.user == owner.key() @ CoreError::Unauthorized
-    )]
-    pub old_biometric_record: Account<'info, BiometricRecord>,
+    let data = order.try_borrow_data()?;
+    require!(data.len() >= 50, CoreError::InvalidUserOrder);
     
-    /// New biometric record (to be created)
-    #[account(
-        init,
-        payer = owner,
-        space = BiometricRecord::SIZE,
-        seeds = [b"biometric", new_biometric_hash.as_ref()],
-        bump
-    )]
-    pub new_biometric_record: Account<'info, BiometricRecord>,
+    let order_proposal_id = u64::from_le_bytes(data[8..16].try_into().unwrap());
+    let target = Pubkey::new_from_array(data[16..48].try_into().unwrap());
     
-    /// Biometric registry (for statistics)
-    #[account(
-        mut,
-        seeds = [b"biometric_registry"],
-        bump = biometric_registry.bump
-    )]
-    pub biometric_registry: Account<'info, BiometricRegistry>,
+    require!(order_proposal_id == proposal_id, CoreError::InvalidUserOrder);
+    // Orders issued before a wallet rotation or recovery still apply
+    require!(
+        target == user.key() || target == user.previous_user_state,
+        CoreError::InvalidUserOrder
+    );
+    require!(data[48] == expected_type, CoreError::InvalidUserOrder);
+    require!(
+        proposal_id > user.last_order_proposal_id,
+        CoreError::UserOrderAlreadyApplied
+    );
     
-    pub system_program: Program<'info, System>,
+    Ok(data[49])
+}
+
+/// Freeze a user and open the appeal window.
+fn suspend_user_state(user: &mut UserState, reason_code: u8, current_time: i64) {
+    user.is_verified = false;
+    user.suspension_reason = reason_code;
+    user.suspended_at = current_time;
+    user.appeal_deadline = current_time + SUSPENSION_APPEAL_WINDOW;
+    user.appealed_at = 0;
 }
 
 // ============================================================================
-// TRANSFER TOKENS CONTEXT
+// DAILY CLAIMS
 // ============================================================================
 
-#[derive(Accounts)]
-pub struct TransferTokens<'info> {
-    /// Sender
-    #[account(mut)]
-    pub sender: Signer<'info>,
+/// Accounts shared by `claim_daily` and `claim_daily_delegated`.
+struct ClaimAccounts<'a, 'info> {
+    user: &'a mut Account<'info, UserState>,
+    protocol_state: &'a mut Account<'info, ProtocolState>,
+    mint: &'a Account<'info, Mint>,
+    user_token_account: AccountInfo<'info>,
+    child_escrow: Option<AccountInfo<'info>>,
+    country_sanction: &'a AccountInfo<'info>,
+    attestation: &'a AccountInfo<'info>,
+    trusted_issuer: &'a AccountInfo<'info>,
+    token_program: &'a Program<'info, Token>,
+}
+
+/// Verify and mint a daily claim. Returns the amount minted.
+fn process_claim(
+    accounts: ClaimAccounts,
+    remaining_accounts: &[AccountInfo],
+    current_time: i64,
+) -> Result<u64> {
+    let user = accounts.user;
+    let protocol = accounts.protocol_state;
     
-    /// Sender state
-    #[account(
-        mut,
-        seeds = [b"user", sender.key().as_ref()],
-        bump = sender_state.bump,
-        constraint = sender_state.owner == sender.key() @ CoreError::Unauthorized
-    )]
-    pub sender_state: Account<'info, UserState>,
+    // ====================================================================
+    // VERIFICATION CHECKS
+    // ====================================================================
     
-    /// Recipient
-    /// CHECK: Just receiving tokens
-    pub recipient: AccountInfo<'info>,
+    require!(user.is_verified, CoreError::UserNotVerified);
     
-    /// Protocol state
-    #[account(
-        mut,
-        seeds = [b"protocol"],
-        bump = protocol_state.bump
-    )]
-    pub protocol_state: Account<'info, ProtocolState>,
+    // Check liveness (with grace period)
+    let liveness_deadline = user.liveness_expires_at + LIVENESS_GRACE_PERIOD;
+    require!(
+        current_time <= liveness_deadline,
+        CoreError::LivenessExpired
+    );
     
-    /// Token mint
-    #[account(
-        mut,
-        address = protocol_state.mint
-    )]
-    pub mint: Account<'info, Mint>,
+    // Check attestation is still valid (revocations take effect here).
+    // Users on a removed issuer can claim until its migration deadline.
+    let migration_deadline = validate_existing_attestation(
+        accounts.attestation,
+        accounts.trusted_issuer,
+        &user.owner,
+        current_time,
+    )?;
     
-    /// Sender's token account
-    #[account(
-        mut,
-        associated_token::mint = mint,
-        associated_token::authority = sender,
-    )]
-    pub sender_token_account: Account<'info, TokenAccount>,
+    // ====================================================================
+    // CALCULATE CLAIMABLE AMOUNT
+    // ====================================================================
     
-    /// Recipient's token account
-    #[account(
-        mut,
-        associated_token::mint = mint,
-        associated_token::authority = recipient,
-    )]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    let seconds_since_last_claim = current_time - user.last_claim_timestamp;
     
-    /// Treasury token account
-    #[account(
-        mut,
-        associated_token::mint = mint,
-        associated_token::authority = protocol_state.treasury,
-    )]
-    pub treasury_token_account: Account<'info, TokenAccount>,
+    // Cap at maximum accumulation window
+    let (claimable_days, _) = accrued_days(user, current_time);
+    
+    require!(claimable_days > 0, CoreError::NothingToClaim);
+    
+    // Base amount (before sanctions)
+    let base_amount = claimable_days * DAILY_AMOUNT;
+    
+    // ====================================================================
+    // CHECK FOR COUNTRY SANCTION
+    // ====================================================================
+    
+    // Sanction accounts for additional citizenships are passed as
+    // remaining accounts; the most favourable percentage applies.
+    
+    let sanction_percentage = effective_sanction_percentage(
+        user,
+        accounts.country_sanction,
+        remaining_accounts,
+        current_time,
+    )?;
+    
+    // ====================================================================
+    // APPLY SANCTION AND MINT
+    // ====================================================================
+    // This is the critical formula - sanctions can only REDUCE, never INCREASE
+    
+    let actual_amount = base_amount * sanction_percentage.min(100) / 100;
+    
+    // Check if user has turned 18 since registration - new tokens then
+    // go to the wallet; escrowed tokens are released by `release_child_escrow`
+    let came_of_age = unlock_if_adult(user, current_time);
+    
+    // Children's tokens go to their escrow
+    let destination = if user.is_child {
+        accounts.child_escrow.ok_or(CoreError::ChildEscrowRequired)?
+    } else {
+        accounts.user_token_account
+    };
+    
+    // Mint tokens
+    let bump = [protocol.bump];
+    let seeds: &[&[u8]] = &[b"protocol", &bump];
+    let signer_seeds = &[seeds];
+    
+    let cpi_accounts = MintTo {
+        mint: accounts.mint.to_account_info(),
+        to: destination,
+        authority: protocol.to_account_info(),
+    };
+    let cpi_program = accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    
+    token::mint_to(cpi_ctx, actual_amount)?;
+    
+    // ====================================================================
+    // UPDATE STATE
+    // ====================================================================
+    
+    // Advance by the whole days elapsed, keeping the partial day.
+    // Days beyond the accumulation window are forfeited here.
+    user.last_claim_timestamp = current_time - seconds_since_last_claim % SECONDS_PER_DAY;
+    user.total_claimed += actual_amount as u128;
+    
+    // If child, track the escrowed amount
+    if user.is_child {
+        user.locked_balance += actual_amount;
+    }
+    
+    protocol.total_minted += actual_amount as u128;
+    
+    // ====================================================================
+    // EMIT EVENTS
+    // ====================================================================
+    // Only after every fallible step: `batch_claim` keeps going when a
+    // claim fails, so earlier events would outlive the failed claim.
+    
+    if came_of_age {
+        emit!(UserCameOfAge {
+            user: user.owner,
+            child_escrow: user.child_escrow,
+            timestamp: current_time,
+        });
+    }
+    
+    if migration_deadline != 0 {
+        emit!(IssuerMigrationRequired {
+            user: user.owner,
+            issuer: user.attestation_issuer,
+            migration_deadline,
+            timestamp: current_time,
+        });
+    }
+    
+    // Warn once per liveness period when expiry is near
+    let status = liveness_status(user, current_time);
+    if (status == LivenessStatus::Warning || status == LivenessStatus::Grace)
+        && user.liveness_warned_at < user.liveness_verified_at
+    {
+        user.liveness_warned_at = current_time;
+        emit!(LivenessWarning {
+            user: user.owner,
+            status,
+            expires_at: user.liveness_expires_at,
+            grace_ends_at: user.liveness_expires_at + LIVENESS_GRACE_PERIOD,
+            timestamp: current_time,
+        });
+    }
+    
+    emit!(DailyClaimed {
+        user: user.owner,
+        days_claimed: claimable_days,
+        base_amount,
+        sanction_percentage,
+        actual_amount,
+        is_locked: user.is_child,
+        timestamp: current_time,
+    });
+    
+    msg!(
+        "Claimed {} days = {} AGORA ({}% UBI)",
+        claimable_days,
+        actual_amount / 1_000_000_000,
+        sanction_percentage
+    );
+    
+    Ok(actual_amount)
+}
+
+/// Liveness status of a user at `current_time`.
+fn liveness_status(user: &UserState, current_time: i64) -> LivenessStatus {
+    if current_time > user.liveness_expires_at + LIVENESS_GRACE_PERIOD {
+        LivenessStatus::Expired
+    } else if current_time > user.liveness_expires_at {
+        LivenessStatus::Grace
+    } else if current_time > user.liveness_expires_at - LIVENESS_WARNING_PERIOD {
+        LivenessStatus::Warning
+    } else {
+        LivenessStatus::Valid
+    }
+}
+
+/// Whole days accrued since the last claim: (claimable, forfeited).
+/// 
+/// Days beyond `MAX_ACCUMULATION_DAYS` are forfeited on the next claim.
+fn accrued_days(user: &UserState, current_time: i64) -> (u64, u64) {
+    let days = ((current_time - user.last_claim_timestamp).max(0) / SECONDS_PER_DAY) as u64;
+    (days.min(MAX_ACCUMULATION_DAYS), days.saturating_sub(MAX_ACCUMULATION_DAYS))
+}
+
+/// Check one `batch_claim` account group before claiming.
+/// 
+/// Verifies what `ClaimDaily` verifies through account constraints:
+/// authorisation, destination, sanction and trusted issuer PDAs. The
+/// destination follows the age unlock `process_claim` is about to apply.
+fn check_batch_group(
+    user: &Account<UserState>,
+    group: &[AccountInfo],
+    kiosk: &Pubkey,
+    mint: &Pubkey,
+    current_time: i64,
+) -> std::result::Result<(), BatchClaimStatus> {
+    let (expected_user, _) = Pubkey::find_program_address(
+        &[b"user".as_ref(), user.owner.as_ref()],
+        &crate::ID,
+    );
+    let wallet = &group[1];
+    let authorised = (wallet.key() == user.owner && wallet.is_signer)
+        || user.claim_delegate == *kiosk;
+    if user.key() != expected_user || !authorised {
+        return Err(BatchClaimStatus::Unauthorized);
+    }
+    
+    let still_child = user.is_child
+        && current_age_days(user, current_time) < CHILD_AGE_THRESHOLD;
+    let destination = if still_child {
+        user.child_escrow
+    } else {
+        get_associated_token_address(&user.owner, mint)
+    };
+    let (expected_sanction, _) = Pubkey::find_program_address(
+        &[b"sanction".as_ref(), user.citizenship.as_ref()],
+        &GOVERNANCE_PROGRAM_ID,
+    );
+    let credential = read_attestation_credential(&group[4]);
+    let (expected_issuer, _) = Pubkey::find_program_address(
+        &[b"trusted_issuer".as_ref(), credential.as_ref()],
+        &GOVERNANCE_PROGRAM_ID,
+    );
+    if !group[0].is_writable
+        || group[2].key() != destination
+        || !group[2].is_writable
+        || group[3].key() != expected_sanction
+        || group[4].key() != user.attestation
+        || group[5].key() != expected_issuer
+    {
+        return Err(BatchClaimStatus::InvalidAccounts);
+    }
+    
+    Ok(())
+}
+
+// ============================================================================
+// COMING OF AGE
+// ============================================================================
+
+/// Current age in days, from the age declared at registration.
+fn current_age_days(user: &UserState, current_time: i64) -> u64 {
+    user.age_in_days_at_registration
+        + ((current_time - user.registration_timestamp) / SECONDS_PER_DAY) as u64
+}
+
+/// Turn a child into an adult if they have reached `CHILD_AGE_THRESHOLD`.
+/// 
+/// Clears `locked_balance`. Returns true if the user came of age now; the
+/// caller emits `UserCameOfAge` once nothing else can fail.
+fn unlock_if_adult(user: &mut UserState, current_time: i64) -> bool {
+    if !user.is_child || current_age_days(user, current_time) < CHILD_AGE_THRESHOLD {
+        return false;
+    }
+    
+    user.is_child = false;
+    user.locked_balance = 0;
+    
+    true
+}
+
+// ============================================================================
+// CHILD ALLOWANCES
+// ============================================================================
+
+/// Offset of the ward's wallet in a guardianship attestation
+/// (first bytes of the attestation data for guardianship schemas)
+const ATTESTATION_WARD_OFFSET: usize = 113;
+
+/// Read the child wallet named by a guardianship attestation.
+fn read_guardianship_ward(attestation: &AccountInfo) -> Result<Pubkey> {
+    require_attestation_schema(attestation, GUARDIANSHIP_SCHEMA_NAME)?;
+    
+    let data = attestation.try_borrow_data()?;
+    require!(
+        data.len() >= ATTESTATION_WARD_OFFSET + 32,
+        CoreError::InvalidGuardianship
+    );
+    Ok(Pubkey::new_from_array(
+        data[ATTESTATION_WARD_OFFSET..ATTESTATION_WARD_OFFSET + 32].try_into().unwrap()
+    ))
+}
+
+/// Read the monthly child allowance cap from Governance CoreParameters.
+/// 
+/// CoreParameters layout (Governance program):
+/// - 8 bytes: discriminator
+/// - 8 bytes: child_allowance_monthly_cap @ 8
+/// 
+/// Returns 0 (no allowance) if the account does not exist yet. Never
+/// more than `MAX_CHILD_ALLOWANCE_MONTHLY_CAP`, whatever Governance stores.
+fn read_child_allowance_cap(core_parameters: &AccountInfo) -> Result<u64> {
+    if *core_parameters.owner != GOVERNANCE_PROGRAM_ID {
+        return Ok(0);
+    }
+    let data = core_parameters.try_borrow_data()?;
+    if data.len() < 16 {
+        return Ok(0);
+    }
+    let cap = u64::from_le_bytes(data[8..16].try_into().unwrap());
+    Ok(cap.min(MAX_CHILD_ALLOWANCE_MONTHLY_CAP))
+}
+
+// ============================================================================
+// DORMANT ACCOUNTS
+// ============================================================================
+
+/// Wallet that receives a dormant account's balance: the heir, or the
+/// treasury if none was set.
+fn dormant_beneficiary(user: &UserState, protocol: &ProtocolState) -> Pubkey {
+    if user.heir == Pubkey::default() {
+        protocol.treasury
+    } else {
+        user.heir
+    }
+}
+
+/// Read the dormant closure period from Governance CoreParameters.
+/// 
+/// CoreParameters layout (Governance program):
+/// - 8 bytes: dormant_closure_period @ 16
+/// 
+/// Fails if the account does not exist yet: no account is closable until
+/// Governance has set a period. Never more than `MAX_DORMANT_CLOSURE_PERIOD`.
+fn read_dormant_closure_period(core_parameters: &AccountInfo) -> Result<i64> {
+    require_keys_eq!(
+        *core_parameters.owner,
+        GOVERNANCE_PROGRAM_ID,
+        CoreError::DormantClosureNotConfigured
+    );
+    let data = core_parameters.try_borrow_data()?;
+    require!(data.len() >= 24, CoreError::DormantClosureNotConfigured);
     
-    /// Optional: Fee state from Governance program
-    /// CHECK: Verified and parsed manually
-    pub fee_state: Option<AccountInfo<'info>>,
+    let period = i64::from_le_bytes(data[16..24].try_into().unwrap());
+    require!(period > 0, CoreError::DormantClosureNotConfigured);
     
-    pub token_program: Program<'info, Token>,
+    Ok(period.min(MAX_DORMANT_CLOSURE_PERIOD))
 }
 
 // ============================================================================
-// UPDATE ATTESTATION CONTEXT (⚫ IMMUTABLE - enables technology migration)
+// ISSUER QUOTAS
 // ============================================================================
+// TrustedIssuer quota fields (Governance program):
+// - 4 bytes: daily_registration_cap    @ 153
+// - 4 bytes: weekly_registration_cap   @ 157
+// - 8 bytes: quotas_updated_at         @ 161
 
-#[derive(Accounts)]
-pub struct UpdateAttestation<'info> {
-    /// User updating their attestation
-    #[account(mut)]
-    pub owner: Signer<'info>,
+const ISSUER_QUOTA_OFFSET: usize = 153;
+const SECONDS_PER_WEEK: i64 = 604_800;
+
+/// Count a registration attempt against the issuer's quotas.
+/// 
+/// Returns false if the attempt is over quota and must be refused.
+/// Refused attempts are only counted when `count_refusal` is set (first
+/// refusal of an attestation). Attempts reaching
+/// `ISSUER_QUOTA_SUSPENSION_MULTIPLIER` times a cap suspend the issuer;
+/// new quotas from Governance lift the suspension.
+fn record_issuer_registration(
+    stats: &mut IssuerStats,
+    trusted_issuer: &AccountInfo,
+    current_time: i64,
+    count_refusal: bool,
+) -> Result<bool> {
+    let data = trusted_issuer.try_borrow_data()?;
+    require!(data.len() >= ISSUER_QUOTA_OFFSET + 16, CoreError::UntrustedIssuer);
+    let daily_cap = u32::from_le_bytes(
+        data[ISSUER_QUOTA_OFFSET..ISSUER_QUOTA_OFFSET + 4].try_into().unwrap()
+    );
+    let weekly_cap = u32::from_le_bytes(
+        data[ISSUER_QUOTA_OFFSET + 4..ISSUER_QUOTA_OFFSET + 8].try_into().unwrap()
+    );
+    let quotas_updated_at = i64::from_le_bytes(
+        data[ISSUER_QUOTA_OFFSET + 8..ISSUER_QUOTA_OFFSET + 16].try_into().unwrap()
+    );
     
-    /// User state - contains current attestation reference
-    #[account(
-        mut,
-        seeds = [b"user", owner.key().as_ref()],
-        bump = user_state.bump,
-        constraint = user_state.owner == owner.key() @ CoreError::Unauthorized
-    )]
-    pub user_state: Account<'info, UserState>,
+    if stats.suspended_at != 0 {
+        require!(quotas_updated_at > stats.suspended_at, CoreError::IssuerSuspended);
+        stats.suspended_at = 0;
+        stats.day_attempts = stats.day_registrations;
+        stats.week_attempts = stats.week_registrations;
+        msg!("Issuer quota suspension lifted by Governance");
+    }
     
-    /// New SAS attestation account
-    /// CHECK: Verified manually in instruction - must be valid SAS attestation
-    /// from trusted issuer, linked to this wallet, not expired, not revoked
-    pub new_attestation: AccountInfo<'info>,
+    // Roll fixed daily / weekly windows
+    let day_start = current_time - current_time.rem_euclid(SECONDS_PER_DAY);
+    if stats.day_start != day_start {
+        stats.day_start = day_start;
+        stats.day_registrations = 0;
+        stats.day_attempts = 0;
+    }
+    let week_start = current_time - current_time.rem_euclid(SECONDS_PER_WEEK);
+    if stats.week_start != week_start {
+        stats.week_start = week_start;
+        stats.week_registrations = 0;
+        stats.week_attempts = 0;
+    }
     
-    /// Trusted issuer account from Governance program
-    /// CHECK: Verified manually - must be active trusted issuer
-    /// PDA seeds: ["trusted_issuer", credential.as_ref()]
-    #[account(
-        seeds = [b"trusted_issuer", trusted_issuer_credential.as_ref()],
-        bump,
-        seeds::program = GOVERNANCE_PROGRAM_ID
-    )]
-    pub trusted_issuer: AccountInfo<'info>,
+    let accepted = stats.day_registrations < daily_cap && stats.week_registrations < weekly_cap;
+    if !accepted && !count_refusal {
+        return Ok(false);
+    }
     
-    /// The credential pubkey used to derive trusted_issuer PDA
-    /// CHECK: Used only for PDA derivation
-    pub trusted_issuer_credential: AccountInfo<'info>,
+    stats.day_attempts = stats.day_attempts.saturating_add(1);
+    stats.week_attempts = stats.week_attempts.saturating_add(1);
     
-    /// SAS Program (for account ownership verification)
-    /// CHECK: Verified against known SAS program ID
-    #[account(
-        constraint = sas_program.key() == SAS_PROGRAM_ID @ CoreError::InvalidAttestation
-    )]
-    pub sas_program: AccountInfo<'info>,
+    if accepted {
+        stats.day_registrations += 1;
+        stats.week_registrations += 1;
+        stats.total_registrations += 1;
+        return Ok(true);
+    }
+    
+    stats.total_refused += 1;
+    
+    let limit = |cap: u32| cap.saturating_mul(ISSUER_QUOTA_SUSPENSION_MULTIPLIER);
+    if stats.day_attempts >= limit(daily_cap) || stats.week_attempts >= limit(weekly_cap) {
+        stats.suspended_at = current_time;
+        emit!(IssuerAutoSuspended {
+            issuer: stats.credential,
+            day_attempts: stats.day_attempts,
+            week_attempts: stats.week_attempts,
+            timestamp: current_time,
+        });
+        msg!("Issuer suspended: registration attempts far above quota");
+    }
+    
+    Ok(false)
 }
 
 // ╔═══════════════════════════════════════════════════════════════════════════╗
@@ -1720,6 +5970,101 @@ pub struct DailyClaimed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ChildGuardianLinked {
+    pub child: Pubkey,
+    pub guardian: Pubkey,
+    pub attestation: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChildAllowanceWithdrawn {
+    pub child: Pubkey,
+    pub guardian: Pubkey,
+    pub amount: u64,
+    pub withdrawn_this_period: u64,
+    pub monthly_cap: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HeirSet {
+    pub user: Pubkey,
+    pub heir: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DormantTransferApproved {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountDormant {
+    pub user: Pubkey,
+    pub liveness_expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DormantAccountClosed {
+    pub user: Pubkey,
+    pub beneficiary: Pubkey,
+    pub wallet_amount: u64,
+    pub escrow_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserCameOfAge {
+    pub user: Pubkey,
+    pub child_escrow: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchClaimed {
+    pub kiosk: Pubkey,
+    pub users: u8,
+    pub claimed: u8,
+    pub total_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimDelegateSet {
+    pub user: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegatedClaim {
+    pub user: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChildEscrowReleased {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LivenessWarning {
+    pub user: Pubkey,
+    pub status: LivenessStatus,
+    pub expires_at: i64,
+    pub grace_ends_at: i64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct LivenessVerified {
     pub user: Pubkey,
@@ -1755,6 +6100,173 @@ pub struct AttestationUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct RegistrationRefused {
+    pub user: Pubkey,
+    pub issuer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerAutoSuspended {
+    pub issuer: Pubkey,
+    pub day_attempts: u32,
+    pub week_attempts: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerMigrationRequired {
+    pub user: Pubkey,
+    pub issuer: Pubkey,
+    pub migration_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestationLapsed {
+    pub user: Pubkey,
+    pub attestation: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CitizenshipAdded {
+    pub user: Pubkey,
+    pub country: [u8; 3],
+    pub attestation: Pubkey,
+    pub citizenship_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WalletRotationRequested {
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WalletRotationCancelled {
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WalletRotated {
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub new_attestation: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryGuardiansSet {
+    pub user: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryProposed {
+    pub user: Pubkey,
+    pub new_owner: Pubkey,
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryApproved {
+    pub user: Pubkey,
+    pub new_owner: Pubkey,
+    pub guardian: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryChallengeStarted {
+    pub user: Pubkey,
+    pub new_owner: Pubkey,
+    pub challenge_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryVetoed {
+    pub user: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryCompleted {
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FraudChallengeOpened {
+    pub challenger: Pubkey,
+    pub user_a: Pubkey,
+    pub user_b: Pubkey,
+    pub bond: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FraudChallengeResolved {
+    pub challenger: Pubkey,
+    pub user_a: Pubkey,
+    pub user_b: Pubkey,
+    pub upheld: bool,
+    pub frozen_user: Pubkey,
+    pub payout: u64,
+    pub resolver: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserSuspended {
+    pub user: Pubkey,
+    pub reason_code: u8,
+    pub proposal_id: u64,
+    pub appeal_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserReinstated {
+    pub user: Pubkey,
+    pub previous_reason: u8,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SuspensionAppealed {
+    pub user: Pubkey,
+    pub reason_code: u8,
+    pub evidence_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CitizenshipUpdated {
+    pub user: Pubkey,
+    pub old_citizenship: [u8; 3],
+    pub new_citizenship: [u8; 3],
+    pub attestation: Pubkey,
+    pub update_count: u8,
+    pub timestamp: i64,
+}
+
 // ╔═══════════════════════════════════════════════════════════════════════════╗
 // ║                                                                           ║
 // ║                              ERROR CODES                                  ║
@@ -1811,6 +6323,129 @@ pub enum CoreError {
     
     #[msg("Attestation has been revoked")]
     AttestationRevoked,
+    
+    #[msg("Maximum number of citizenships reached")]
+    TooManyCitizenships,
+    
+    #[msg("User already holds this citizenship")]
+    CitizenshipAlreadyHeld,
+    
+    #[msg("Sanction account for a citizenship is missing or wrong")]
+    MissingSanctionAccount,
+    
+    #[msg("Citizenship can only be changed once per year")]
+    CitizenshipUpdateTooSoon,
+    
+    #[msg("Invalid rotation target - wallet already has an account")]
+    InvalidRotationTarget,
+    
+    #[msg("Wallet rotation cooldown has not elapsed")]
+    RotationCooldownActive,
+    
+    #[msg("Invalid recovery guardians (3-5 verified users, majority threshold)")]
+    InvalidRecoveryGuardians,
+    
+    #[msg("Signer is not a recovery guardian")]
+    NotRecoveryGuardian,
+    
+    #[msg("A recovery is already in progress")]
+    RecoveryInProgress,
+    
+    #[msg("No recovery is pending")]
+    NoRecoveryPending,
+    
+    #[msg("Guardian already approved this recovery")]
+    RecoveryAlreadyApproved,
+    
+    #[msg("Recovery challenge period has not ended")]
+    RecoveryChallengeActive,
+    
+    #[msg("Invalid fraud challenge (accounts must be ordered, distinct and verified)")]
+    InvalidFraudChallenge,
+    
+    #[msg("Fraud challenge already resolved")]
+    ChallengeAlreadyResolved,
+    
+    #[msg("Invalid Governance user order")]
+    InvalidUserOrder,
+    
+    #[msg("User order has already been applied")]
+    UserOrderAlreadyApplied,
+    
+    #[msg("User is not suspended")]
+    UserNotSuspended,
+    
+    #[msg("Appeal window closed or appeal already filed")]
+    AppealNotAllowed,
+    
+    #[msg("Attestation is already flagged as lapsed")]
+    AttestationAlreadyFlagged,
+    
+    #[msg("Attestation is still valid")]
+    AttestationStillValid,
+    
+    #[msg("Issuer is suspended for exceeding registration quotas")]
+    IssuerSuspended,
+    
+    #[msg("Child escrow account required")]
+    ChildEscrowRequired,
+    
+    #[msg("User has not turned 18 yet")]
+    NotYetAdult,
+    
+    #[msg("User is not a child")]
+    NotAChild,
+    
+    #[msg("Invalid guardianship attestation or guardian")]
+    InvalidGuardianship,
+    
+    #[msg("Signer is not the child's guardian")]
+    NotChildGuardian,
+    
+    #[msg("Withdrawal exceeds the monthly child allowance")]
+    AllowanceCapExceeded,
+    
+    #[msg("Invalid or unauthorised claim delegate")]
+    InvalidClaimDelegate,
+    
+    #[msg("Too many users in batch claim")]
+    BatchTooLarge,
+    
+    #[msg("Batch claim accounts are incomplete")]
+    InvalidBatchAccounts,
+    
+    #[msg("Account is already dormant")]
+    AlreadyDormant,
+    
+    #[msg("Liveness has not expired beyond the grace period")]
+    LivenessNotExpired,
+    
+    #[msg("Account is not dormant")]
+    NotDormant,
+    
+    #[msg("Dormant closure period has not passed")]
+    DormantClosureLocked,
+    
+    #[msg("Dormant closure period not configured by Governance")]
+    DormantClosureNotConfigured,
+    
+    #[msg("Beneficiary does not match the user's heir")]
+    InvalidHeir,
+    
+    #[msg("Attestation does not use the required schema")]
+    WrongAttestationSchema,
+    
+    #[msg("Issuer registration quota exceeded")]
+    IssuerQuotaExceeded,
+    
+    #[msg("Only children have an escrow account")]
+    ChildEscrowNotAllowed,
+    
+    #[msg("The current guardian must consent to being replaced")]
+    GuardianConsentRequired,
+    
+    #[msg("Token account is not the user's associated token account")]
+    InvalidTokenAccount,
 }
 
 // ╔═══════════════════════════════════════════════════════════════════════════╗
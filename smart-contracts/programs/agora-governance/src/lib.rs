@@ -77,6 +77,13 @@
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_instruction;
+use anchor_lang::solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use anchor_lang::solana_program::hash::hash;
+use agora_core::{
+    BiometricRecord as CoreBiometricRecord, UserState as CoreUserState,
+    MAX_CHILD_ALLOWANCE_MONTHLY_CAP, MAX_DORMANT_CLOSURE_PERIOD, SAS_PROGRAM_ID,
+    SAS_CREDENTIAL_OFFSET, SAS_DATA_OFFSET, SAS_EXPIRY_OFFSET, SAS_NONCE_OFFSET, SAS_REVOKED_OFFSET,
+};
 
 declare_id!("AGoRAGovXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
 
@@ -160,6 +167,23 @@ pub const PROPOSAL_BOND_CONSTITUTIONAL: u64 = 100_000_000_000_000;
 /// 🔴 CONSTITUTIONAL (>75%) to change
 pub const PROPOSAL_BOND_SANCTION: u64 = 75_000_000_000_000;
 
+// Proposer cancellation - a proposer may withdraw early, but not for free.
+// The retained portion of the bond goes to treasury so that create-and-cancel
+// cannot be used as a free way to put items in front of voters.
+
+/// Percentage of the bond returned when the proposer cancels (50%)
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const CANCEL_BOND_RETURN_PCT: u64 = 50;
+
+/// Cancellation is only possible within this time after creation (24 hours)
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const CANCEL_WINDOW: i64 = 86400;
+
+/// Cancellation is only possible while yes+no votes are below this share
+/// of the quorum (in basis points, 1000 = 10%)
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const CANCEL_MAX_QUORUM_PCT: u64 = 1000;
+
 // ============================================================================
 // 1.3 DYNAMIC QUORUM SYSTEM
 // ============================================================================
@@ -336,6 +360,11 @@ pub const DIAMOND_SPONSOR_DISCOUNT: u64 = 0;   // FREE
 pub const FREE_TIER_DAILY_TX: u8 = 5;          // Max 5 subsidized TX/day
 pub const MAX_SUBSIDY_PER_TX: u64 = 10_000;    // Max 0.00001 SOL per TX
 
+/// Reimbursement per delegated claim made for a user: the base fee of the
+/// claim transaction (one signature, 5,000 lamports), so delegates cannot
+/// profit from claiming
+pub const CLAIM_DELEGATE_REIMBURSEMENT: u64 = 5_000;
+
 // ============================================================================
 // 1.9 TREASURY MINT RATE
 // ============================================================================
@@ -365,8 +394,90 @@ pub const MAX_SANCTION_DURATION: i64 = 365 * 86400;
 /// 🟠 SANCTION (>67%) to change
 pub const DEFAULT_SANCTION_DURATION: i64 = 365 * 86400;
 
+/// Maximum number of steps in a graduated sanction schedule
+/// (e.g. 50% for 3 months, then 80% for the rest of the term)
+/// ⚫ Core reads exactly this many steps - changing it requires a new layout
+pub const MAX_SANCTION_STEPS: usize = 4;
+
+// ============================================================================
+// 1.11 EXECUTION TIMELOCK & VETO
+// ============================================================================
+//
+// Passed proposals cannot be executed immediately. Each proposal type has a
+// mandatory delay between `finalize_proposal` and execution. During that
+// delay the proposal can be vetoed by the guardian council or by a passed
+// counter-proposal that required a higher quorum.
+// 🔴 CONSTITUTIONAL (>75%) to change timelock and veto parameters
+
+/// Standard proposal timelock (none)
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const TIMELOCK_STANDARD: i64 = 0;
+
+/// Treasury proposal timelock (none)
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const TIMELOCK_TREASURY: i64 = 0;
+
+/// Sanction proposal timelock (7 days)
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const TIMELOCK_SANCTION: i64 = 7 * 86400;
+
+/// Constitutional proposal timelock (14 days)
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const TIMELOCK_CONSTITUTIONAL: i64 = 14 * 86400;
+
+/// Maximum number of guardian council members
+pub const MAX_GUARDIANS: usize = 7;
+
+// ============================================================================
+// 1.12 IDENTITY VERIFICATION
+// ============================================================================
+// Voters must be verified Core users with a valid SAS attestation.
+// ⚫ Layouts below are defined by Core and SAS
+
+/// Time users of a removed issuer have to move to another issuer (90 days).
+/// Core keeps accepting their existing attestations until then.
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const ISSUER_MIGRATION_GRACE_PERIOD: i64 = 90 * 86400;
+
+/// Default registration quotas for a newly added issuer.
+/// Core rejects registrations beyond these caps and suspends the issuer
+/// when attempts exceed them by a large margin.
+/// 🔴 CONSTITUTIONAL (>75%) to change per issuer (`set_issuer_quotas`)
+pub const DEFAULT_ISSUER_DAILY_CAP: u32 = 2_000;
+pub const DEFAULT_ISSUER_WEEKLY_CAP: u32 = 10_000;
+
+/// SOL bond an issuer posts when added (100 SOL, in lamports).
+/// Held in the TrustedIssuer account; slashable for fraudulent attestations.
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const ISSUER_BOND_AMOUNT: u64 = 100_000_000_000;
+
+/// Delay between an issuer's voluntary exit and bond withdrawal (180 days).
+/// Leaves time to prove fraud committed before the exit.
+/// 🔴 CONSTITUTIONAL (>75%) to change
+pub const ISSUER_BOND_WITHDRAWAL_DELAY: i64 = 180 * 86400;
+
+// The Solana Attestation Service (SAS) program ID is Core's `SAS_PROGRAM_ID`
+
+// ============================================================================
+// 1.13 CORE PARAMETERS
+// ============================================================================
+// Tunable values Core reads from the CoreParameters account.
+// Core keeps its own hard limits; these can only loosen or tighten within them.
+
+/// Default monthly allowance a guardian may withdraw from a child's escrow
+/// (3,000 AGORA = one month of UBI)
+/// 🔴 CONSTITUTIONAL (>75%) to change (`set_child_allowance_cap`)
+pub const DEFAULT_CHILD_ALLOWANCE_MONTHLY_CAP: u64 = 3_000_000_000_000;
+
+/// Default time a dormant account waits before it can be closed (365 days)
+/// 🔴 CONSTITUTIONAL (>75%) to change (`set_dormant_closure_period`)
+pub const DEFAULT_DORMANT_CLOSURE_PERIOD: i64 = 31_536_000;
+
+/// Shortest dormant closure period governance can set (180 days)
+pub const MIN_DORMANT_CLOSURE_PERIOD: i64 = 15_552_000;
+
 // ============================================================================
-// 1.11 HELPER FUNCTIONS FOR DYNAMIC QUORUM
+// 1.14 HELPER FUNCTIONS FOR DYNAMIC QUORUM
 // ============================================================================
 
 /// Calculate dynamic quorum based on total users
@@ -424,6 +535,16 @@ pub fn get_bond_amount(proposal_type: &ProposalType) -> u64 {
     }
 }
 
+/// Get execution timelock for proposal type
+pub fn get_execution_delay(proposal_type: &ProposalType) -> i64 {
+    match proposal_type {
+        ProposalType::Standard => TIMELOCK_STANDARD,
+        ProposalType::Treasury => TIMELOCK_TREASURY,
+        ProposalType::Sanction => TIMELOCK_SANCTION,
+        ProposalType::Constitutional => TIMELOCK_CONSTITUTIONAL,
+    }
+}
+
 // ╔═══════════════════════════════════════════════════════════════════════════╗
 // ║                                                                           ║
 // ║                    SECTION 2: PROGRAM ENTRY POINT                         ║
@@ -510,9 +631,10 @@ pub mod agora_governance {
     /// * `title` - Short title (64 bytes max)
     /// * `description_hash` - IPFS hash of full description
     /// * `treasury_amount` - Amount requested (for Treasury proposals)
-    /// * `treasury_recipient` - Recipient (for Treasury proposals)
+    /// * `treasury_recipient` - Recipient (for Treasury proposals; slashed bond for SlashIssuer)
     /// * `sanction_country` - Country code (for Sanction proposals)
     /// * `sanction_ubi_pct` - UBI percentage (for Sanction proposals)
+    /// * `terms` - Action and action-specific commitments (see `ProposalTerms`)
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
         proposal_type: ProposalType,
@@ -522,7 +644,23 @@ pub mod agora_governance {
         treasury_recipient: Pubkey,
         sanction_country: [u8; 3],
         sanction_ubi_pct: u8,
+        terms: ProposalTerms,
     ) -> Result<()> {
+        let ProposalTerms {
+            action,
+            veto_target_id,
+            upgrade_buffer,
+            upgrade_buffer_hash,
+            sanction_duration,
+            sanction_schedule,
+            target_account,
+            reason_code,
+            council_members,
+            council_veto_threshold,
+            parameter_values,
+            issuer_authority,
+            issuer_name,
+        } = terms;
         let current_time = Clock::get()?.unix_timestamp;
         let proposer_state = &mut ctx.accounts.proposer_state;
         let registry = &mut ctx.accounts.proposal_registry;
@@ -570,15 +708,189 @@ pub mod agora_governance {
         let quorum = calculate_quorum(total_users, &proposal_type);
         let approval_threshold = get_approval_threshold(&proposal_type);
         let voting_period = get_voting_period(&proposal_type);
+        let execution_delay = get_execution_delay(&proposal_type);
         
         // ====================================================================
         // VALIDATE SANCTION PARAMETERS
         // ====================================================================
         
+        let sanction_duration = match action {
+            ProposalAction::ImposeSanction | ProposalAction::RenewSanction => {
+                require!(
+                    proposal_type == ProposalType::Sanction,
+                    GovernanceError::WrongProposalType
+                );
+                validate_sanction_terms(sanction_ubi_pct, sanction_duration, &sanction_schedule)?
+            },
+            ProposalAction::LiftSanction => {
+                require!(
+                    proposal_type == ProposalType::Sanction,
+                    GovernanceError::WrongProposalType
+                );
+                0
+            },
+            _ => 0,
+        };
+        
         if let ProposalType::Sanction = proposal_type {
             require!(
-                sanction_ubi_pct >= MIN_SANCTION_UBI_PCT && sanction_ubi_pct <= 99,
-                GovernanceError::InvalidSanctionPercentage
+                action != ProposalAction::None,
+                GovernanceError::WrongProposalType
+            );
+        }
+        
+        // ====================================================================
+        // VALIDATE USER ORDERS
+        // ====================================================================
+        // Suspending or reinstating a human's UBI is a Sanction-level decision
+        
+        if let ProposalAction::SuspendUser | ProposalAction::ReinstateUser = action {
+            require!(
+                proposal_type == ProposalType::Sanction,
+                GovernanceError::WrongProposalType
+            );
+            require!(
+                target_account != Pubkey::default(),
+                GovernanceError::InvalidUserOrder
+            );
+            require!(
+                action == ProposalAction::ReinstateUser || reason_code != 0,
+                GovernanceError::InvalidUserOrder
+            );
+        }
+        
+        // ====================================================================
+        // VALIDATE ISSUER SLASHING
+        // ====================================================================
+        // Slashed bonds go to `treasury_recipient`
+        
+        if action == ProposalAction::SlashIssuer {
+            require!(
+                proposal_type == ProposalType::Constitutional
+                    || proposal_type == ProposalType::Sanction,
+                GovernanceError::WrongProposalType
+            );
+            require!(
+                target_account != Pubkey::default()
+                    && treasury_recipient != Pubkey::default(),
+                GovernanceError::InvalidIssuerBond
+            );
+        }
+        
+        // ====================================================================
+        // VALIDATE GUARDIAN COUNCIL
+        // ====================================================================
+        // The new council is committed here and voted on as-is
+        
+        if action == ProposalAction::SetGuardianCouncil {
+            require!(
+                proposal_type == ProposalType::Constitutional,
+                GovernanceError::WrongProposalType
+            );
+            validate_guardian_council(&council_members, council_veto_threshold)?;
+        }
+        
+        // ====================================================================
+        // VALIDATE CONSTITUTIONAL ACTIONS
+        // ====================================================================
+        // Executors read their parameters from the proposal, never from args
+        
+        match action {
+            ProposalAction::UpdateFeeParameters => {
+                require!(
+                    proposal_type == ProposalType::Constitutional,
+                    GovernanceError::WrongProposalType
+                );
+                require!(parameter_values[0] <= 100, GovernanceError::FeeTooHigh);
+                require!(parameter_values[1] <= 100, GovernanceError::InvalidBurnPercentage);
+            },
+            ProposalAction::ProgramUpgrade => {
+                require!(
+                    proposal_type == ProposalType::Constitutional,
+                    GovernanceError::WrongProposalType
+                );
+                require!(
+                    upgrade_buffer != Pubkey::default(),
+                    GovernanceError::UpgradeBufferMismatch
+                );
+            },
+            ProposalAction::AddTrustedIssuer => {
+                require!(
+                    proposal_type == ProposalType::Constitutional,
+                    GovernanceError::WrongProposalType
+                );
+                require!(
+                    target_account != Pubkey::default()
+                        && issuer_authority != Pubkey::default(),
+                    GovernanceError::InvalidIssuerBond
+                );
+            },
+            ProposalAction::RemoveTrustedIssuer => {
+                require!(
+                    proposal_type == ProposalType::Constitutional,
+                    GovernanceError::WrongProposalType
+                );
+                require!(
+                    target_account != Pubkey::default(),
+                    GovernanceError::IssuerNotActive
+                );
+            },
+            ProposalAction::SetIssuerQuotas => {
+                require!(
+                    proposal_type == ProposalType::Constitutional,
+                    GovernanceError::WrongProposalType
+                );
+                require!(
+                    target_account != Pubkey::default()
+                        && parameter_values[1] <= u32::MAX as u64
+                        && parameter_values[0] > 0
+                        && parameter_values[0] <= parameter_values[1],
+                    GovernanceError::InvalidIssuerQuota
+                );
+            },
+            ProposalAction::SetChildAllowanceCap => {
+                require!(
+                    proposal_type == ProposalType::Constitutional,
+                    GovernanceError::WrongProposalType
+                );
+                require!(
+                    parameter_values[0] <= MAX_CHILD_ALLOWANCE_MONTHLY_CAP,
+                    GovernanceError::InvalidChildAllowanceCap
+                );
+            },
+            ProposalAction::SetDormantClosurePeriod => {
+                require!(
+                    proposal_type == ProposalType::Constitutional,
+                    GovernanceError::WrongProposalType
+                );
+                require!(
+                    parameter_values[0] >= MIN_DORMANT_CLOSURE_PERIOD as u64
+                        && parameter_values[0] <= MAX_DORMANT_CLOSURE_PERIOD as u64,
+                    GovernanceError::InvalidDormantClosurePeriod
+                );
+            },
+            _ => {},
+        }
+        
+        // ====================================================================
+        // VALIDATE VETO TARGET
+        // ====================================================================
+        // A counter-proposal can only target a proposal that already exists
+        
+        require!(
+            veto_target_id < registry.next_proposal_id,
+            GovernanceError::InvalidVetoTarget
+        );
+        
+        // ====================================================================
+        // VALIDATE PROGRAM UPGRADE
+        // ====================================================================
+        // Only ProgramUpgrade proposals may carry a program upgrade
+        
+        if upgrade_buffer != Pubkey::default() {
+            require!(
+                action == ProposalAction::ProgramUpgrade,
+                GovernanceError::WrongProposalType
             );
         }
         
@@ -619,10 +931,26 @@ pub mod agora_governance {
         proposal.created_at = current_time;
         proposal.voting_ends_at = current_time + voting_period;
         proposal.executed_at = 0;
+        proposal.execution_delay = execution_delay;
+        proposal.executable_after = 0;
+        proposal.veto_target_id = veto_target_id;
+        proposal.guardian_vetoes = 0;
+        proposal.upgrade_buffer = upgrade_buffer;
+        proposal.upgrade_buffer_hash = upgrade_buffer_hash;
         proposal.treasury_amount = treasury_amount;
         proposal.treasury_recipient = treasury_recipient;
         proposal.sanction_country = sanction_country;
         proposal.sanction_ubi_pct = sanction_ubi_pct;
+        proposal.action = action;
+        proposal.sanction_duration = sanction_duration;
+        proposal.sanction_schedule = sanction_schedule;
+        proposal.target_account = target_account;
+        proposal.reason_code = reason_code;
+        proposal.council_members = council_members;
+        proposal.council_veto_threshold = council_veto_threshold;
+        proposal.parameter_values = parameter_values;
+        proposal.issuer_authority = issuer_authority;
+        proposal.issuer_name = issuer_name;
         proposal.bump = ctx.bumps.proposal;
         
         // Update registry
@@ -667,13 +995,25 @@ pub mod agora_governance {
         let current_time = Clock::get()?.unix_timestamp;
         let proposal = &mut ctx.accounts.proposal;
         let vote_record = &mut ctx.accounts.vote_record;
-        let voter_state = &ctx.accounts.voter_state;
         
         // ====================================================================
         // VALIDATION
         // ====================================================================
         
-        require!(voter_state.is_verified, GovernanceError::VoterNotVerified);
+        // Suspended Core users (is_verified = false) and users whose
+        // attestation lapsed cannot vote
+        require_verified_core_user(
+            &ctx.accounts.voter_state,
+            &ctx.accounts.voter.key(),
+            &ctx.accounts.governance_state.core_program,
+        )?;
+        require_valid_attestation(
+            &ctx.accounts.voter_state,
+            &ctx.accounts.voter_attestation,
+            &ctx.accounts.voter_issuer,
+            &ctx.accounts.voter.key(),
+            current_time,
+        )?;
         require!(proposal.status == ProposalStatus::Active, GovernanceError::ProposalNotActive);
         require!(current_time <= proposal.voting_ends_at, GovernanceError::VotingEnded);
         require!(choice <= 2, GovernanceError::InvalidVoteChoice);
@@ -792,6 +1132,11 @@ pub mod agora_governance {
         proposal.executed_at = current_time;
         proposal.bond_resolved = true;
         
+        // Start the execution timelock (veto window) for passed proposals
+        if new_status == ProposalStatus::Passed {
+            proposal.executable_after = current_time + proposal.execution_delay;
+        }
+        
         // ====================================================================
         // UPDATE PROPOSER REPUTATION
         // ====================================================================
@@ -807,7 +1152,7 @@ pub mod agora_governance {
         if return_bond {
             // Return bond to proposer
             let seeds = &[
-                b"governance",
+                b"governance".as_ref(),
                 &[ctx.accounts.governance_state.bump],
             ];
             let signer_seeds = &[&seeds[..]];
@@ -828,7 +1173,7 @@ pub mod agora_governance {
         } else {
             // Forfeit bond to treasury
             let seeds = &[
-                b"governance",
+                b"governance".as_ref(),
                 &[ctx.accounts.governance_state.bump],
             ];
             let signer_seeds = &[&seeds[..]];
@@ -861,6 +1206,7 @@ pub mod agora_governance {
             approved,
             reputation_change,
             bond_returned: return_bond,
+            executable_after: proposal.executable_after,
             timestamp: current_time,
         });
         
@@ -871,11 +1217,20 @@ pub mod agora_governance {
         if proposal.status == ProposalStatus::Passed {
             match proposal.proposal_type {
                 ProposalType::Sanction => {
-                    msg!("Sanction proposal passed - create sanction account separately");
+                    msg!(
+                        "Sanction proposal passed - create sanction account after {}",
+                        proposal.executable_after
+                    );
                 },
                 ProposalType::Treasury => {
                     msg!("Treasury proposal passed - execute transfer separately");
                 },
+                ProposalType::Constitutional => {
+                    msg!(
+                        "Constitutional proposal passed - executable after {}",
+                        proposal.executable_after
+                    );
+                },
                 _ => {}
             }
         }
@@ -883,6 +1238,121 @@ pub mod agora_governance {
         Ok(())
     }
 
+    /// Cancel an active proposal (proposer only).
+    /// 
+    /// Lets a proposer withdraw a proposal they no longer stand behind
+    /// instead of waiting for `finalize_proposal` to expire it.
+    /// 
+    /// # Conditions
+    /// 
+    /// - Only the original proposer can cancel
+    /// - Proposal must still be Active
+    /// - Must be within `CANCEL_WINDOW` of creation
+    /// - Yes+no votes must be below `CANCEL_MAX_QUORUM_PCT` of quorum
+    /// 
+    /// # Bond
+    /// 
+    /// `CANCEL_BOND_RETURN_PCT` of the bond is returned to the proposer,
+    /// the remainder is forfeited to treasury. Reputation is unchanged.
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        let registry = &mut ctx.accounts.proposal_registry;
+        
+        // ====================================================================
+        // VALIDATION
+        // ====================================================================
+        
+        require!(
+            proposal.proposer == ctx.accounts.proposer.key(),
+            GovernanceError::NotProposer
+        );
+        require!(proposal.status == ProposalStatus::Active, GovernanceError::ProposalNotActive);
+        require!(
+            current_time <= proposal.created_at + CANCEL_WINDOW,
+            GovernanceError::CancellationWindowClosed
+        );
+        
+        let total_votes = proposal.votes_yes + proposal.votes_no;
+        let quorum_pct = (total_votes * 10000) / proposal.quorum_required.max(1);
+        require!(
+            quorum_pct < CANCEL_MAX_QUORUM_PCT,
+            GovernanceError::CancellationWindowClosed
+        );
+        
+        // ====================================================================
+        // SPLIT BOND
+        // ====================================================================
+        
+        let returned_amount = proposal.bond_amount * CANCEL_BOND_RETURN_PCT / 100;
+        let forfeited_amount = proposal.bond_amount - returned_amount;
+        
+        let seeds = &[
+            b"governance".as_ref(),
+            &[ctx.accounts.governance_state.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        if returned_amount > 0 {
+            let cpi_accounts = anchor_spl::token::Transfer {
+                from: ctx.accounts.bond_escrow.to_account_info(),
+                to: ctx.accounts.proposer_token_account.to_account_info(),
+                authority: ctx.accounts.governance_state.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            anchor_spl::token::transfer(cpi_ctx, returned_amount)?;
+            
+            registry.total_bonds_returned += returned_amount;
+        }
+        
+        if forfeited_amount > 0 {
+            let cpi_accounts = anchor_spl::token::Transfer {
+                from: ctx.accounts.bond_escrow.to_account_info(),
+                to: ctx.accounts.treasury_token_account.to_account_info(),
+                authority: ctx.accounts.governance_state.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            anchor_spl::token::transfer(cpi_ctx, forfeited_amount)?;
+            
+            registry.total_bonds_forfeited += forfeited_amount;
+        }
+        
+        // ====================================================================
+        // UPDATE STATE
+        // ====================================================================
+        
+        proposal.status = ProposalStatus::Cancelled;
+        proposal.executed_at = current_time;
+        proposal.bond_resolved = true;
+        
+        registry.active_proposals = registry.active_proposals.saturating_sub(1);
+        
+        emit!(ProposalCancelled {
+            id: proposal.id,
+            proposer: proposal.proposer,
+            bond_returned: returned_amount,
+            bond_forfeited: forfeited_amount,
+            timestamp: current_time,
+        });
+        
+        msg!(
+            "Proposal #{} cancelled. {} AGORA returned, {} AGORA forfeited",
+            proposal.id,
+            returned_amount / 1_000_000_000,
+            forfeited_amount / 1_000_000_000
+        );
+        
+        Ok(())
+    }
+
     // ========================================================================
     // 2.3 COUNTRY SANCTIONS
     // ========================================================================
@@ -903,32 +1373,34 @@ pub mod agora_governance {
     /// 
     /// - Sanctions REDUCE UBI, never to zero (minimum 1%)
     /// - Core reads this account but Governance cannot force Core to do anything
-    /// - Sanction has expiration date
+    /// - Sanction expires after the duration chosen in the proposal
+    /// - An optional schedule changes the UBI percentage over the term;
+    ///   Core evaluates it at claim time
     pub fn impose_sanction(ctx: Context<ImposeSanction>) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
-        let proposal = &ctx.accounts.proposal;
+        let proposal = &mut ctx.accounts.proposal;
         let sanction = &mut ctx.accounts.country_sanction;
         
-        // Verify proposal passed
-        require!(
-            proposal.status == ProposalStatus::Passed,
-            GovernanceError::ProposalNotPassed
-        );
+        // Verify proposal passed and timelock elapsed
+        require_executable(proposal, current_time)?;
         require!(
-            proposal.proposal_type == ProposalType::Sanction,
+            proposal.proposal_type == ProposalType::Sanction
+                && proposal.action == ProposalAction::ImposeSanction,
             GovernanceError::WrongProposalType
         );
         
+        proposal.status = ProposalStatus::Executed;
+        
         // Create sanction
         sanction.country_code = proposal.sanction_country;
-        sanction.ubi_percentage = proposal.sanction_ubi_pct;
         sanction.imposed_at = current_time;
-        sanction.expires_at = current_time + DEFAULT_SANCTION_DURATION;
-        sanction.proposal_id = proposal.id;
         sanction.is_active = true;
         sanction.lifted_early = false;
         sanction.lifted_at = 0;
+        sanction.renewal_count = 0;
+        sanction.sequence = 0;
         sanction.bump = ctx.bumps.country_sanction;
+        apply_sanction_terms(sanction, proposal, current_time);
         
         emit!(SanctionImposed {
             country_code: sanction.country_code,
@@ -947,19 +1419,150 @@ pub mod agora_governance {
         Ok(())
     }
 
+    /// Impose a new sanction on a country whose previous sanction ended.
+    /// 
+    /// 🔴 REQUIRES: SANCTION proposal (>67% approval, 5% quorum, 75K bond)
+    /// 
+    /// The CountrySanction PDA for a country exists forever once created,
+    /// so a new `ImposeSanction` proposal re-initialises the inactive or
+    /// expired record. The previous terms are archived first in a
+    /// SanctionHistory account keyed by country and sequence number.
+    pub fn reimpose_sanction(ctx: Context<ReimposeSanction>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        let sanction = &mut ctx.accounts.country_sanction;
+        let history = &mut ctx.accounts.sanction_history;
+        
+        // Verify proposal passed and timelock elapsed
+        require_executable(proposal, current_time)?;
+        require!(
+            proposal.proposal_type == ProposalType::Sanction
+                && proposal.action == ProposalAction::ImposeSanction,
+            GovernanceError::WrongProposalType
+        );
+        
+        // Previous sanction must be over (lifted or expired)
+        require!(
+            !sanction.is_active || current_time >= sanction.expires_at,
+            GovernanceError::SanctionStillActive
+        );
+        
+        proposal.status = ProposalStatus::Executed;
+        
+        // Archive previous terms
+        history.country_code = sanction.country_code;
+        history.sequence = sanction.sequence;
+        history.ubi_percentage = sanction.ubi_percentage;
+        history.imposed_at = sanction.imposed_at;
+        history.expires_at = sanction.expires_at;
+        history.proposal_id = sanction.proposal_id;
+        history.lifted_early = sanction.lifted_early;
+        history.lifted_at = sanction.lifted_at;
+        history.renewal_count = sanction.renewal_count;
+        history.schedule = sanction.schedule;
+        history.archived_at = current_time;
+        history.bump = ctx.bumps.sanction_history;
+        
+        // Re-initialise with the new terms
+        sanction.sequence = sanction.sequence.saturating_add(1);
+        sanction.imposed_at = current_time;
+        sanction.is_active = true;
+        sanction.lifted_early = false;
+        sanction.lifted_at = 0;
+        sanction.renewal_count = 0;
+        apply_sanction_terms(sanction, proposal, current_time);
+        
+        emit!(SanctionImposed {
+            country_code: sanction.country_code,
+            ubi_percentage: sanction.ubi_percentage,
+            proposal_id: proposal.id,
+            expires_at: sanction.expires_at,
+            timestamp: current_time,
+        });
+        
+        msg!(
+            "Sanction re-imposed on country {:?} (sequence {}). UBI reduced to {}%",
+            sanction.country_code,
+            sanction.sequence,
+            sanction.ubi_percentage
+        );
+        
+        Ok(())
+    }
+
+    /// Renew an active sanction with fresh terms (requires new DAO vote).
+    /// 
+    /// 🔴 REQUIRES: SANCTION proposal (>67% approval, 5% quorum, 75K bond)
+    /// 
+    /// The renewal proposal must be a `RenewSanction` action for the same
+    /// country. Its UBI percentage, duration and schedule replace the
+    /// current terms, starting now.
+    pub fn renew_sanction(ctx: Context<RenewSanction>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        let sanction = &mut ctx.accounts.country_sanction;
+        
+        // Verify proposal passed and timelock elapsed
+        require_executable(proposal, current_time)?;
+        require!(
+            proposal.proposal_type == ProposalType::Sanction
+                && proposal.action == ProposalAction::RenewSanction,
+            GovernanceError::WrongProposalType
+        );
+        require!(
+            proposal.sanction_country == sanction.country_code,
+            GovernanceError::SanctionCountryMismatch
+        );
+        require!(sanction.is_active, GovernanceError::SanctionNotActive);
+        
+        proposal.status = ProposalStatus::Executed;
+        
+        apply_sanction_terms(sanction, proposal, current_time);
+        sanction.renewal_count = sanction.renewal_count.saturating_add(1);
+        
+        emit!(SanctionRenewed {
+            country_code: sanction.country_code,
+            ubi_percentage: sanction.ubi_percentage,
+            proposal_id: proposal.id,
+            renewal_count: sanction.renewal_count,
+            expires_at: sanction.expires_at,
+            timestamp: current_time,
+        });
+        
+        msg!(
+            "Sanction on country {:?} renewed until {}",
+            sanction.country_code,
+            sanction.expires_at
+        );
+        
+        Ok(())
+    }
+
     /// Lift a sanction early (requires new DAO vote).
     /// 
     /// 🔴 REQUIRES: SANCTION proposal (>67% approval, 5% quorum, 75K bond)
+    /// 
+    /// The lift proposal must be a `LiftSanction` action naming the same
+    /// country. It is marked Executed, so it cannot lift anything else.
     pub fn lift_sanction(ctx: Context<LiftSanction>) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
         let sanction = &mut ctx.accounts.country_sanction;
-        let lift_proposal = &ctx.accounts.lift_proposal;
+        let lift_proposal = &mut ctx.accounts.lift_proposal;
         
-        // Verify lift proposal passed
+        // Verify lift proposal passed and timelock elapsed
+        require_executable(lift_proposal, current_time)?;
         require!(
-            lift_proposal.status == ProposalStatus::Passed,
-            GovernanceError::ProposalNotPassed
+            lift_proposal.proposal_type == ProposalType::Sanction
+                && lift_proposal.action == ProposalAction::LiftSanction,
+            GovernanceError::WrongProposalType
+        );
+        require!(
+            lift_proposal.sanction_country == sanction.country_code,
+            GovernanceError::SanctionCountryMismatch
         );
+        require!(sanction.is_active, GovernanceError::SanctionNotActive);
+        
+        lift_proposal.status = ProposalStatus::Executed;
         
         // Lift sanction
         sanction.is_active = false;
@@ -1084,34 +1687,107 @@ pub mod agora_governance {
         
         Ok(())
     }
-
-    // ========================================================================
-    // 2.5 FEE MANAGEMENT
-    // ========================================================================
-    // 🔴 CONSTITUTIONAL (>75%) - Fee changes require high supermajority
-
-    /// Update fee parameters (requires Constitutional proposal).
+    
+    /// Reimburse a claim delegate's fees from the gas pool.
     /// 
-    /// 🟠 REQUIRES: CONSTITUTIONAL proposal (>75% approval, 10% quorum, 100K bond)
-    pub fn update_fee_parameters(
-        ctx: Context<UpdateFeeParameters>,
-        new_base_rate: u64,
-        new_burn_pct: u64,
-    ) -> Result<()> {
+    /// Pays `CLAIM_DELEGATE_REIMBURSEMENT` once per delegated claim, read
+    /// from the user's Core UserState (`claim_delegate`,
+    /// `last_delegated_claim_at`).
+    pub fn reimburse_claim_delegate(ctx: Context<ReimburseClaimDelegate>) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
-        let fee_state = &mut ctx.accounts.fee_state;
-        let proposal = &ctx.accounts.proposal;
+        let gas_pool = &mut ctx.accounts.gas_pool_state;
+        let record = &mut ctx.accounts.claim_reimbursement;
         
-        // Verify proposal passed
+        require!(!gas_pool.emergency_paused, GovernanceError::GasPoolPaused);
+        
+        const DELEGATE: usize = CoreUserState::CLAIM_DELEGATE_OFFSET;
+        const LAST_CLAIM: usize = CoreUserState::LAST_DELEGATED_CLAIM_OFFSET;
+        let data = ctx.accounts.user_state.try_borrow_data()?;
+        require!(data.len() >= LAST_CLAIM + 8, GovernanceError::InvalidClaimReimbursement);
+        let delegate = Pubkey::new_from_array(data[DELEGATE..DELEGATE + 32].try_into().unwrap());
+        let last_delegated_claim_at = i64::from_le_bytes(
+            data[LAST_CLAIM..LAST_CLAIM + 8].try_into().unwrap()
+        );
+        drop(data);
+        
+        require_keys_eq!(
+            delegate,
+            ctx.accounts.delegate.key(),
+            GovernanceError::InvalidClaimReimbursement
+        );
         require!(
-            proposal.status == ProposalStatus::Passed,
-            GovernanceError::ProposalNotPassed
+            last_delegated_claim_at > record.last_reimbursed_claim,
+            GovernanceError::InvalidClaimReimbursement
+        );
+        
+        let amount = CLAIM_DELEGATE_REIMBURSEMENT;
+        require!(gas_pool.available_balance >= amount, GovernanceError::InvalidAmount);
+        
+        let bump = [ctx.bumps.gas_pool_vault];
+        let seeds: &[&[u8]] = &[b"gas_pool_vault", &bump];
+        let transfer_ix = system_instruction::transfer(
+            &ctx.accounts.gas_pool_vault.key(),
+            &ctx.accounts.delegate.key(),
+            amount,
         );
+        anchor_lang::solana_program::program::invoke_signed(
+            &transfer_ix,
+            &[
+                ctx.accounts.gas_pool_vault.to_account_info(),
+                ctx.accounts.delegate.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[seeds],
+        )?;
+        
+        if record.user_state == Pubkey::default() {
+            record.user_state = ctx.accounts.user_state.key();
+            record.bump = ctx.bumps.claim_reimbursement;
+        }
+        record.last_reimbursed_claim = last_delegated_claim_at;
+        record.total_reimbursed += amount;
+        
+        gas_pool.available_balance -= amount;
+        gas_pool.total_subsidized += amount;
+        gas_pool.total_transactions_subsidized += 1;
+        
+        emit!(ClaimDelegateReimbursed {
+            user_state: record.user_state,
+            delegate,
+            amount,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    // ========================================================================
+    // 2.5 FEE MANAGEMENT
+    // ========================================================================
+    // 🔴 CONSTITUTIONAL (>75%) - Fee changes require high supermajority
+
+    /// Update fee parameters (requires Constitutional proposal).
+    /// 
+    /// 🟠 REQUIRES: CONSTITUTIONAL proposal with action UpdateFeeParameters
+    /// 
+    /// The new base rate and burn percentage are `parameter_values[0]` and
+    /// `parameter_values[1]` of the proposal.
+    pub fn update_fee_parameters(ctx: Context<UpdateFeeParameters>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let fee_state = &mut ctx.accounts.fee_state;
+        let proposal = &mut ctx.accounts.proposal;
+        
+        // Verify proposal passed and timelock elapsed
+        require_executable(proposal, current_time)?;
         require!(
-            proposal.proposal_type == ProposalType::Constitutional,
+            proposal.proposal_type == ProposalType::Constitutional
+                && proposal.action == ProposalAction::UpdateFeeParameters,
             GovernanceError::WrongProposalType
         );
         
+        let new_base_rate = proposal.parameter_values[0];
+        let new_burn_pct = proposal.parameter_values[1];
+        
         // Validate parameters
         require!(new_base_rate <= 100, GovernanceError::FeeTooHigh); // Max 1%
         require!(new_burn_pct <= 100, GovernanceError::InvalidBurnPercentage);
@@ -1120,6 +1796,7 @@ pub mod agora_governance {
         fee_state.base_fee_rate = new_base_rate;
         fee_state.burn_percentage = new_burn_pct;
         fee_state.last_updated = current_time;
+        proposal.status = ProposalStatus::Executed;
         
         emit!(FeeParametersUpdated {
             base_fee_rate: new_base_rate,
@@ -1131,6 +1808,314 @@ pub mod agora_governance {
         Ok(())
     }
 
+    // ========================================================================
+    // 2.6 EXECUTION TIMELOCK & VETO
+    // ========================================================================
+    // 🔴 CONSTITUTIONAL (>75%) - Guardian council membership
+
+    /// Initialize the guardian council (called once during setup).
+    /// 
+    /// The council starts empty. Members are appointed later by a
+    /// Constitutional proposal via `set_guardian_council`.
+    pub fn initialize_guardian_council(ctx: Context<InitializeGuardianCouncil>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let council = &mut ctx.accounts.guardian_council;
+        
+        council.members = [Pubkey::default(); MAX_GUARDIANS];
+        council.member_count = 0;
+        council.veto_threshold = 0;
+        council.proposal_id = 0;
+        council.updated_at = current_time;
+        council.bump = ctx.bumps.guardian_council;
+        
+        emit!(GuardianCouncilUpdated {
+            member_count: 0,
+            veto_threshold: 0,
+            proposal_id: 0,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Replace the guardian council (requires Constitutional proposal).
+    /// 
+    /// 🔴 REQUIRES: CONSTITUTIONAL proposal with action SetGuardianCouncil
+    /// 
+    /// Members and threshold come from the proposal (`council_members`,
+    /// `council_veto_threshold`), so anyone can trigger execution.
+    pub fn set_guardian_council(ctx: Context<SetGuardianCouncil>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        let council = &mut ctx.accounts.guardian_council;
+        
+        require_executable(proposal, current_time)?;
+        require!(
+            proposal.proposal_type == ProposalType::Constitutional
+                && proposal.action == ProposalAction::SetGuardianCouncil,
+            GovernanceError::WrongProposalType
+        );
+        
+        let veto_threshold = proposal.council_veto_threshold;
+        let member_count = validate_guardian_council(&proposal.council_members, veto_threshold)?;
+        
+        council.members = proposal.council_members;
+        council.member_count = member_count;
+        council.veto_threshold = veto_threshold;
+        council.proposal_id = proposal.id;
+        council.updated_at = current_time;
+        
+        proposal.status = ProposalStatus::Executed;
+        
+        emit!(GuardianCouncilUpdated {
+            member_count: council.member_count,
+            veto_threshold,
+            proposal_id: proposal.id,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+
+    /// Cast a guardian veto against a passed proposal in its timelock.
+    /// 
+    /// Once `veto_threshold` guardians have vetoed, the proposal moves to
+    /// `Vetoed` and can never be executed. Each guardian can veto once.
+    pub fn guardian_veto(ctx: Context<GuardianVeto>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        let council = &ctx.accounts.guardian_council;
+        let veto_record = &mut ctx.accounts.veto_record;
+        let guardian = ctx.accounts.guardian.key();
+        
+        require!(
+            council.veto_threshold > 0
+                && council.members[..council.member_count as usize].contains(&guardian),
+            GovernanceError::NotGuardian
+        );
+        require_veto_window(proposal, current_time)?;
+        
+        veto_record.guardian = guardian;
+        veto_record.proposal_id = proposal.id;
+        veto_record.vetoed_at = current_time;
+        veto_record.bump = ctx.bumps.veto_record;
+        
+        proposal.guardian_vetoes = proposal.guardian_vetoes.saturating_add(1);
+        
+        emit!(GuardianVetoCast {
+            proposal_id: proposal.id,
+            guardian,
+            vetoes: proposal.guardian_vetoes,
+            veto_threshold: council.veto_threshold,
+            timestamp: current_time,
+        });
+        
+        if proposal.guardian_vetoes >= council.veto_threshold {
+            proposal.status = ProposalStatus::Vetoed;
+            
+            emit!(ProposalVetoed {
+                id: proposal.id,
+                by_guardians: true,
+                counter_proposal_id: 0,
+                timestamp: current_time,
+            });
+            
+            msg!("Proposal #{} vetoed by guardian council", proposal.id);
+        }
+        
+        Ok(())
+    }
+
+    /// Veto a passed proposal with a passed counter-proposal.
+    /// 
+    /// The counter-proposal must name the target in `veto_target_id` and
+    /// must have required a higher quorum than the target. The counter
+    /// veto takes effect immediately (it is not subject to its own
+    /// timelock, since blocking execution cannot change protocol state).
+    pub fn veto_by_counter_proposal(ctx: Context<VetoByCounterProposal>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        let counter = &mut ctx.accounts.counter_proposal;
+        
+        require!(
+            counter.status == ProposalStatus::Passed,
+            GovernanceError::ProposalNotPassed
+        );
+        require!(
+            counter.veto_target_id != 0 && counter.veto_target_id == proposal.id,
+            GovernanceError::InvalidVetoTarget
+        );
+        require!(
+            counter.quorum_required > proposal.quorum_required,
+            GovernanceError::InsufficientVetoQuorum
+        );
+        require_veto_window(proposal, current_time)?;
+        
+        proposal.status = ProposalStatus::Vetoed;
+        counter.status = ProposalStatus::Executed;
+        
+        emit!(ProposalVetoed {
+            id: proposal.id,
+            by_guardians: false,
+            counter_proposal_id: counter.id,
+            timestamp: current_time,
+        });
+        
+        msg!("Proposal #{} vetoed by counter-proposal #{}", proposal.id, counter.id);
+        
+        Ok(())
+    }
+
+    // ========================================================================
+    // 2.7 PROGRAM UPGRADES
+    // ========================================================================
+    // 🔴 CONSTITUTIONAL (>75%) - Governance upgrades itself only by DAO vote
+
+    /// Upgrade this program from a buffer committed in a Constitutional proposal.
+    /// 
+    /// 🔴 REQUIRES: CONSTITUTIONAL proposal (>75% approval, 10% quorum, 100K bond)
+    /// 
+    /// The upgrade authority of this program is the `["upgrade_authority"]`
+    /// PDA, so no key holder can upgrade it. The proposal commits to the
+    /// exact buffer account AND the SHA-256 of its program bytes (everything
+    /// after the loader's buffer metadata header), so the buffer cannot be
+    /// rewritten between the vote and the upgrade.
+    /// 
+    /// The buffer's authority must be set to the upgrade authority PDA
+    /// before execution (the loader enforces this).
+    /// 
+    /// Anyone can trigger execution once the timelock has elapsed. The
+    /// buffer's rent goes back to the proposer.
+    /// Hashing a large buffer needs a raised compute budget.
+    pub fn execute_program_upgrade(ctx: Context<ExecuteProgramUpgrade>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        
+        // ====================================================================
+        // VERIFY PROPOSAL
+        // ====================================================================
+        
+        require_executable(proposal, current_time)?;
+        require!(
+            proposal.proposal_type == ProposalType::Constitutional
+                && proposal.action == ProposalAction::ProgramUpgrade,
+            GovernanceError::WrongProposalType
+        );
+        require!(
+            proposal.upgrade_buffer != Pubkey::default()
+                && ctx.accounts.buffer.key() == proposal.upgrade_buffer,
+            GovernanceError::UpgradeBufferMismatch
+        );
+        
+        // ====================================================================
+        // VERIFY BUFFER CONTENTS
+        // ====================================================================
+        
+        let buffer_hash = {
+            let buffer_data = ctx.accounts.buffer.try_borrow_data()?;
+            let metadata_len = UpgradeableLoaderState::size_of_buffer_metadata();
+            require!(
+                buffer_data.len() > metadata_len,
+                GovernanceError::UpgradeBufferMismatch
+            );
+            hash(&buffer_data[metadata_len..]).to_bytes()
+        };
+        
+        require!(
+            buffer_hash == proposal.upgrade_buffer_hash,
+            GovernanceError::UpgradeBufferHashMismatch
+        );
+        
+        proposal.status = ProposalStatus::Executed;
+        
+        // ====================================================================
+        // UPGRADE VIA BPF UPGRADEABLE LOADER
+        // ====================================================================
+        
+        let upgrade_ix = bpf_loader_upgradeable::upgrade(
+            &crate::ID,
+            &ctx.accounts.buffer.key(),
+            &ctx.accounts.upgrade_authority.key(),
+            &ctx.accounts.spill.key(),
+        );
+        
+        let seeds = &[
+            b"upgrade_authority".as_ref(),
+            &[ctx.bumps.upgrade_authority],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        anchor_lang::solana_program::program::invoke_signed(
+            &upgrade_ix,
+            &[
+                ctx.accounts.program_data.to_account_info(),
+                ctx.accounts.program_account.to_account_info(),
+                ctx.accounts.buffer.to_account_info(),
+                ctx.accounts.spill.to_account_info(),
+                ctx.accounts.rent.to_account_info(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.upgrade_authority.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+        
+        emit!(ProgramUpgraded {
+            proposal_id: proposal.id,
+            buffer: proposal.upgrade_buffer,
+            buffer_hash,
+            timestamp: current_time,
+        });
+        
+        msg!("Governance program upgraded by proposal #{}", proposal.id);
+        
+        Ok(())
+    }
+
+    // ========================================================================
+    // 2.8 USER DUE PROCESS
+    // ========================================================================
+
+    /// Issue a suspension or reinstatement order for a Core user.
+    /// 
+    /// 🟠 REQUIRES: SANCTION proposal with action SuspendUser / ReinstateUser
+    /// 
+    /// Governance cannot write Core accounts. This creates a UserOrder
+    /// account that Core's `suspend_user` / `reinstate_user` read and apply.
+    /// PDA: ["user_order", proposal_id (u64 LE)]
+    pub fn issue_user_order(ctx: Context<IssueUserOrder>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        let order = &mut ctx.accounts.user_order;
+        
+        require_executable(proposal, current_time)?;
+        let order_type = match proposal.action {
+            ProposalAction::SuspendUser => UserOrderType::Suspend,
+            ProposalAction::ReinstateUser => UserOrderType::Reinstate,
+            _ => return Err(GovernanceError::WrongProposalType.into()),
+        };
+        
+        proposal.status = ProposalStatus::Executed;
+        
+        order.proposal_id = proposal.id;
+        order.target_account = proposal.target_account;
+        order.order_type = order_type;
+        order.reason_code = proposal.reason_code;
+        order.issued_at = current_time;
+        order.bump = ctx.bumps.user_order;
+        
+        emit!(UserOrderIssued {
+            proposal_id: proposal.id,
+            target_account: order.target_account,
+            suspend: order.order_type == UserOrderType::Suspend,
+            reason_code: order.reason_code,
+            timestamp: current_time,
+        });
+        
+        msg!("User order issued by proposal #{}", proposal.id);
+        
+        Ok(())
+    }
+
     // ========================================================================
     // TRUSTED ISSUER MANAGEMENT
     // ========================================================================
@@ -1141,31 +2126,50 @@ pub mod agora_governance {
     /// This allows DAO to approve new identity verification providers
     /// (e.g., adding RNS.ID alongside Civic).
     /// 
-    /// 🔴 REQUIRES: CONSTITUTIONAL proposal (>75% approval, 10% quorum, 100K bond)
+    /// 🔴 REQUIRES: CONSTITUTIONAL proposal with action AddTrustedIssuer
     /// 
-    /// # Arguments
-    /// * `credential` - The SAS credential PDA of the issuer
-    /// * `name` - Human-readable name (max 32 bytes)
-    pub fn add_trusted_issuer(
-        ctx: Context<AddTrustedIssuer>,
-        credential: Pubkey,
-        name: [u8; 32],
-    ) -> Result<()> {
+    /// The proposal commits to:
+    /// * `target_account` - The SAS credential PDA of the issuer
+    /// * `issuer_name` - Human-readable name (max 32 bytes)
+    /// * `issuer_authority` - Issuer's signing key (resolves fraud challenges in Core)
+    /// 
+    /// The issuer authority must sign and posts `ISSUER_BOND_AMOUNT` SOL,
+    /// held in the TrustedIssuer account.
+    pub fn add_trusted_issuer(ctx: Context<AddTrustedIssuer>) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
-        let proposal = &ctx.accounts.proposal;
+        let proposal = &mut ctx.accounts.proposal;
         let issuer = &mut ctx.accounts.trusted_issuer;
         let registry = &mut ctx.accounts.issuer_registry;
         
         // Verify proposal passed with Constitutional requirements
+        require_executable(proposal, current_time)?;
         require!(
-            proposal.status == ProposalStatus::Passed,
-            GovernanceError::ProposalNotPassed
-        );
-        require!(
-            proposal.proposal_type == ProposalType::Constitutional,
+            proposal.proposal_type == ProposalType::Constitutional
+                && proposal.action == ProposalAction::AddTrustedIssuer,
             GovernanceError::WrongProposalType
         );
         
+        proposal.status = ProposalStatus::Executed;
+        
+        let credential = proposal.target_account;
+        let name = proposal.issuer_name;
+        let authority = proposal.issuer_authority;
+        
+        // Post issuer bond
+        let transfer_ix = system_instruction::transfer(
+            &ctx.accounts.issuer_authority.key(),
+            &issuer.key(),
+            ISSUER_BOND_AMOUNT,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                ctx.accounts.issuer_authority.to_account_info(),
+                issuer.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        
         // Initialize issuer record
         issuer.credential = credential;
         issuer.name = name;
@@ -1175,6 +2179,13 @@ pub mod agora_governance {
         issuer.deactivated_at = 0;
         issuer.deactivation_proposal_id = 0;
         issuer.total_attestations = 0;
+        issuer.authority = authority;
+        issuer.migration_deadline = 0;
+        issuer.daily_registration_cap = DEFAULT_ISSUER_DAILY_CAP;
+        issuer.weekly_registration_cap = DEFAULT_ISSUER_WEEKLY_CAP;
+        issuer.quotas_updated_at = current_time;
+        issuer.bond_amount = ISSUER_BOND_AMOUNT;
+        issuer.exit_requested_at = 0;
         issuer.bump = ctx.bumps.trusted_issuer;
         
         // Update registry
@@ -1185,6 +2196,7 @@ pub mod agora_governance {
         emit!(TrustedIssuerAdded {
             credential,
             name,
+            authority,
             proposal_id: proposal.id,
             timestamp: current_time,
         });
@@ -1199,28 +2211,30 @@ pub mod agora_governance {
     /// This allows DAO to deactivate an identity provider that is no longer
     /// trusted or has been compromised.
     /// 
-    /// NOTE: Existing attestations from this issuer remain valid until they expire.
-    /// Users should update to attestations from active issuers.
+    /// NOTE: Existing attestations from this issuer remain valid in Core until
+    /// the issuer's migration deadline (`ISSUER_MIGRATION_GRACE_PERIOD`).
+    /// Affected users must update to attestations from active issuers before then.
     /// 
-    /// 🔴 REQUIRES: CONSTITUTIONAL proposal (>75% approval, 10% quorum, 100K bond)
+    /// 🔴 REQUIRES: CONSTITUTIONAL proposal with action RemoveTrustedIssuer
+    /// (`target_account` = the TrustedIssuer account)
     pub fn remove_trusted_issuer(
         ctx: Context<RemoveTrustedIssuer>,
     ) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
-        let proposal = &ctx.accounts.proposal;
+        let proposal = &mut ctx.accounts.proposal;
         let issuer = &mut ctx.accounts.trusted_issuer;
         let registry = &mut ctx.accounts.issuer_registry;
         
         // Verify proposal passed with Constitutional requirements
+        require_executable(proposal, current_time)?;
         require!(
-            proposal.status == ProposalStatus::Passed,
-            GovernanceError::ProposalNotPassed
-        );
-        require!(
-            proposal.proposal_type == ProposalType::Constitutional,
+            proposal.proposal_type == ProposalType::Constitutional
+                && proposal.action == ProposalAction::RemoveTrustedIssuer,
             GovernanceError::WrongProposalType
         );
         
+        proposal.status = ProposalStatus::Executed;
+        
         // Verify issuer is currently active
         require!(
             issuer.is_active,
@@ -1231,6 +2245,7 @@ pub mod agora_governance {
         issuer.is_active = false;
         issuer.deactivated_at = current_time;
         issuer.deactivation_proposal_id = proposal.id;
+        issuer.migration_deadline = current_time + ISSUER_MIGRATION_GRACE_PERIOD;
         
         // Update registry
         registry.active_issuers = registry.active_issuers.saturating_sub(1);
@@ -1239,6 +2254,7 @@ pub mod agora_governance {
         emit!(TrustedIssuerRemoved {
             credential: issuer.credential,
             proposal_id: proposal.id,
+            migration_deadline: issuer.migration_deadline,
             timestamp: current_time,
         });
         
@@ -1247,53 +2263,380 @@ pub mod agora_governance {
         Ok(())
     }
     
-    /// Initialize the trusted issuer registry (called once during setup).
+    /// Set an issuer's registration quotas (requires Constitutional proposal).
     /// 
-    /// Also adds Civic as the initial trusted issuer.
-    pub fn initialize_issuer_registry(
-        ctx: Context<InitializeIssuerRegistry>,
-        civic_credential: Pubkey,
-    ) -> Result<()> {
+    /// Core enforces these caps in `register_user`. Setting quotas also lifts
+    /// an automatic quota suspension in Core (Core compares its suspension
+    /// time with `quotas_updated_at`).
+    /// 
+    /// 🔴 REQUIRES: CONSTITUTIONAL proposal (>75% approval, 10% quorum, 100K bond)
+    pub fn set_issuer_quotas(ctx: Context<SetIssuerQuotas>) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
-        let registry = &mut ctx.accounts.issuer_registry;
-        let civic_issuer = &mut ctx.accounts.civic_issuer;
+        let proposal = &mut ctx.accounts.proposal;
+        let issuer = &mut ctx.accounts.trusted_issuer;
         
-        // Initialize registry
-        registry.total_issuers = 1;
-        registry.active_issuers = 1;
-        registry.created_at = current_time;
-        registry.last_updated = current_time;
-        registry.bump = ctx.bumps.issuer_registry;
+        require_executable(proposal, current_time)?;
+        require!(
+            proposal.proposal_type == ProposalType::Constitutional
+                && proposal.action == ProposalAction::SetIssuerQuotas,
+            GovernanceError::WrongProposalType
+        );
         
-        // Initialize Civic as first trusted issuer
-        civic_issuer.credential = civic_credential;
-        civic_issuer.name = *b"Civic                           "; // 32 bytes padded
-        civic_issuer.approved_at = current_time;
-        civic_issuer.proposal_id = 0; // Genesis - no proposal
-        civic_issuer.is_active = true;
-        civic_issuer.deactivated_at = 0;
-        civic_issuer.deactivation_proposal_id = 0;
-        civic_issuer.total_attestations = 0;
-        civic_issuer.bump = ctx.bumps.civic_issuer;
+        // Quotas were validated in create_proposal and voted on as-is
+        let daily_cap = proposal.parameter_values[0] as u32;
+        let weekly_cap = proposal.parameter_values[1] as u32;
         
-        emit!(IssuerRegistryInitialized {
-            civic_credential,
+        issuer.daily_registration_cap = daily_cap;
+        issuer.weekly_registration_cap = weekly_cap;
+        issuer.quotas_updated_at = current_time;
+        proposal.status = ProposalStatus::Executed;
+        
+        emit!(IssuerQuotasUpdated {
+            credential: issuer.credential,
+            daily_cap,
+            weekly_cap,
+            proposal_id: proposal.id,
             timestamp: current_time,
         });
         
-        msg!("Issuer registry initialized with Civic as first trusted issuer");
+        msg!("Issuer quotas set: {}/day, {}/week", daily_cap, weekly_cap);
         
         Ok(())
     }
-}
-
-// ╔═══════════════════════════════════════════════════════════════════════════╗
-// ║                                                                           ║
-// ║                    SECTION 3: ACCOUNT STRUCTURES                          ║
-// ║                                                                           ║
-// ╚═══════════════════════════════════════════════════════════════════════════╝
-
-// ============================================================================
+    
+    /// Copy an issuer's registration count from Core (anyone can call).
+    /// 
+    /// Core counts registrations per issuer in its IssuerStats PDA
+    /// (["issuer_stats", credential]); this keeps `total_attestations` current.
+    pub fn sync_issuer_stats(ctx: Context<SyncIssuerStats>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let issuer = &mut ctx.accounts.trusted_issuer;
+        
+        // IssuerStats layout (Core):
+        // - 8 bytes: discriminator
+        // - 32 bytes: credential           @ 8
+        // - 8 bytes: total_registrations   @ 40
+        let data = ctx.accounts.issuer_stats.try_borrow_data()?;
+        require!(data.len() >= 48, GovernanceError::InvalidIssuerStats);
+        let credential = Pubkey::new_from_array(data[8..40].try_into().unwrap());
+        require_keys_eq!(credential, issuer.credential, GovernanceError::InvalidIssuerStats);
+        let total_registrations = u64::from_le_bytes(data[40..48].try_into().unwrap());
+        
+        issuer.total_attestations = total_registrations;
+        
+        emit!(IssuerStatsSynced {
+            credential: issuer.credential,
+            total_attestations: total_registrations,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+    
+    /// Slash an issuer's bond after proven fraudulent attestations.
+    /// 
+    /// 🔴 REQUIRES: CONSTITUTIONAL or SANCTION proposal with action SlashIssuer
+    /// 
+    /// The whole remaining bond goes to the proposal's `treasury_recipient`.
+    /// Works during the exit delay, so an issuer cannot escape by exiting.
+    pub fn slash_issuer_bond(ctx: Context<SlashIssuerBond>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        let issuer = &mut ctx.accounts.trusted_issuer;
+        
+        require_executable(proposal, current_time)?;
+        require!(
+            proposal.action == ProposalAction::SlashIssuer,
+            GovernanceError::WrongProposalType
+        );
+        
+        let amount = issuer.bond_amount;
+        require!(amount > 0, GovernanceError::InvalidIssuerBond);
+        
+        issuer.bond_amount = 0;
+        **issuer.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient.try_borrow_mut_lamports()? += amount;
+        proposal.status = ProposalStatus::Executed;
+        
+        emit!(IssuerBondSlashed {
+            credential: issuer.credential,
+            amount,
+            recipient: ctx.accounts.recipient.key(),
+            proposal_id: proposal.id,
+            timestamp: current_time,
+        });
+        
+        msg!("Issuer bond slashed: {} lamports", amount);
+        
+        Ok(())
+    }
+    
+    /// Voluntarily stop issuing for AGORA (signed by the issuer authority).
+    /// 
+    /// Deactivates the issuer (existing attestations get the usual migration
+    /// window) and starts the bond withdrawal delay. Also used by issuers
+    /// removed by Governance to start reclaiming their bond.
+    pub fn request_issuer_exit(ctx: Context<IssuerExit>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let issuer = &mut ctx.accounts.trusted_issuer;
+        let registry = &mut ctx.accounts.issuer_registry;
+        
+        require!(issuer.exit_requested_at == 0, GovernanceError::IssuerExitPending);
+        
+        if issuer.is_active {
+            issuer.is_active = false;
+            issuer.deactivated_at = current_time;
+            issuer.migration_deadline = current_time + ISSUER_MIGRATION_GRACE_PERIOD;
+            registry.active_issuers = registry.active_issuers.saturating_sub(1);
+            registry.last_updated = current_time;
+        }
+        issuer.exit_requested_at = current_time;
+        
+        emit!(IssuerExitRequested {
+            credential: issuer.credential,
+            withdrawable_at: current_time + ISSUER_BOND_WITHDRAWAL_DELAY,
+            timestamp: current_time,
+        });
+        
+        msg!("Issuer exit requested: {:?}", issuer.credential);
+        
+        Ok(())
+    }
+    
+    /// Withdraw an exited issuer's bond after `ISSUER_BOND_WITHDRAWAL_DELAY`.
+    pub fn withdraw_issuer_bond(ctx: Context<WithdrawIssuerBond>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let issuer = &mut ctx.accounts.trusted_issuer;
+        
+        require!(issuer.exit_requested_at != 0, GovernanceError::BondWithdrawalLocked);
+        require!(
+            current_time >= issuer.exit_requested_at + ISSUER_BOND_WITHDRAWAL_DELAY,
+            GovernanceError::BondWithdrawalLocked
+        );
+        
+        let amount = issuer.bond_amount;
+        require!(amount > 0, GovernanceError::InvalidIssuerBond);
+        
+        issuer.bond_amount = 0;
+        **issuer.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.issuer_authority.to_account_info().try_borrow_mut_lamports()? += amount;
+        
+        emit!(IssuerBondWithdrawn {
+            credential: issuer.credential,
+            amount,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+    
+    /// Initialize the trusted issuer registry (called once during setup).
+    /// 
+    /// Also adds Civic as the initial trusted issuer. Like any issuer added
+    /// by Governance, Civic's authority must sign and posts
+    /// `ISSUER_BOND_AMOUNT` SOL, so the genesis issuer can be slashed too.
+    pub fn initialize_issuer_registry(
+        ctx: Context<InitializeIssuerRegistry>,
+        civic_credential: Pubkey,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let registry = &mut ctx.accounts.issuer_registry;
+        let civic_issuer = &mut ctx.accounts.civic_issuer;
+        let civic_authority = ctx.accounts.civic_authority.key();
+        
+        // Post Civic's issuer bond
+        let transfer_ix = system_instruction::transfer(
+            &civic_authority,
+            &civic_issuer.key(),
+            ISSUER_BOND_AMOUNT,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                ctx.accounts.civic_authority.to_account_info(),
+                civic_issuer.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        
+        // Initialize registry
+        registry.total_issuers = 1;
+        registry.active_issuers = 1;
+        registry.created_at = current_time;
+        registry.last_updated = current_time;
+        registry.bump = ctx.bumps.issuer_registry;
+        
+        // Initialize Civic as first trusted issuer
+        civic_issuer.credential = civic_credential;
+        civic_issuer.name = *b"Civic                           "; // 32 bytes padded
+        civic_issuer.approved_at = current_time;
+        civic_issuer.proposal_id = 0; // Genesis - no proposal
+        civic_issuer.is_active = true;
+        civic_issuer.deactivated_at = 0;
+        civic_issuer.deactivation_proposal_id = 0;
+        civic_issuer.total_attestations = 0;
+        civic_issuer.authority = civic_authority;
+        civic_issuer.migration_deadline = 0;
+        civic_issuer.daily_registration_cap = DEFAULT_ISSUER_DAILY_CAP;
+        civic_issuer.weekly_registration_cap = DEFAULT_ISSUER_WEEKLY_CAP;
+        civic_issuer.quotas_updated_at = current_time;
+        civic_issuer.bond_amount = ISSUER_BOND_AMOUNT;
+        civic_issuer.exit_requested_at = 0;
+        civic_issuer.bump = ctx.bumps.civic_issuer;
+        
+        emit!(IssuerRegistryInitialized {
+            civic_credential,
+            timestamp: current_time,
+        });
+        
+        msg!("Issuer registry initialized with Civic as first trusted issuer");
+        
+        Ok(())
+    }
+
+    // ========================================================================
+    // 2.9 CORE PARAMETERS
+    // ========================================================================
+    // 🔴 CONSTITUTIONAL (>75%) - Values Core reads to protect children's funds
+    // and the balances of dormant accounts
+
+    /// Initialize Core parameters with defaults (called once during setup).
+    pub fn initialize_core_parameters(ctx: Context<InitializeCoreParameters>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let params = &mut ctx.accounts.core_parameters;
+        
+        params.child_allowance_monthly_cap = DEFAULT_CHILD_ALLOWANCE_MONTHLY_CAP;
+        params.dormant_closure_period = DEFAULT_DORMANT_CLOSURE_PERIOD;
+        params.last_updated = current_time;
+        params.bump = ctx.bumps.core_parameters;
+        
+        msg!("Core parameters initialized");
+        
+        Ok(())
+    }
+    
+    /// Set the monthly allowance guardians may withdraw from a child's escrow.
+    /// 
+    /// 🔴 REQUIRES: CONSTITUTIONAL proposal (>75% approval, 10% quorum, 100K bond)
+    /// 
+    /// 0 disables guardian allowances. The cap is committed in the proposal
+    /// (`parameter_values[0]`) and at most Core's
+    /// `MAX_CHILD_ALLOWANCE_MONTHLY_CAP`.
+    pub fn set_child_allowance_cap(ctx: Context<SetCoreParameter>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let params = &mut ctx.accounts.core_parameters;
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require_executable(proposal, current_time)?;
+        require!(
+            proposal.proposal_type == ProposalType::Constitutional
+                && proposal.action == ProposalAction::SetChildAllowanceCap,
+            GovernanceError::WrongProposalType
+        );
+        
+        let new_cap = proposal.parameter_values[0];
+        
+        params.child_allowance_monthly_cap = new_cap;
+        params.last_updated = current_time;
+        proposal.status = ProposalStatus::Executed;
+        
+        emit!(ChildAllowanceCapUpdated {
+            monthly_cap: new_cap,
+            proposal_id: proposal.id,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+    
+    /// Set how long an account must be dormant before Core lets it be closed.
+    /// 
+    /// 🔴 REQUIRES: CONSTITUTIONAL proposal (>75% approval, 10% quorum, 100K bond)
+    /// 
+    /// At least `MIN_DORMANT_CLOSURE_PERIOD`, so users who lapse on liveness
+    /// have time to come back before their account is closed, and at most
+    /// Core's `MAX_DORMANT_CLOSURE_PERIOD`. The period is committed in the
+    /// proposal (`parameter_values[0]`).
+    pub fn set_dormant_closure_period(ctx: Context<SetCoreParameter>) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let params = &mut ctx.accounts.core_parameters;
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require_executable(proposal, current_time)?;
+        require!(
+            proposal.proposal_type == ProposalType::Constitutional
+                && proposal.action == ProposalAction::SetDormantClosurePeriod,
+            GovernanceError::WrongProposalType
+        );
+        
+        let new_period = proposal.parameter_values[0] as i64;
+        
+        params.dormant_closure_period = new_period;
+        params.last_updated = current_time;
+        proposal.status = ProposalStatus::Executed;
+        
+        emit!(DormantClosurePeriodUpdated {
+            closure_period: new_period,
+            proposal_id: proposal.id,
+            timestamp: current_time,
+        });
+        
+        Ok(())
+    }
+    
+    /// Close Governance accounts of a Core user closed as dormant
+    /// (anyone can call; rent goes to the caller).
+    /// 
+    /// Requires the user's retired Core BiometricRecord and their closed
+    /// UserState. Closes the user's ClaimReimbursement and/or a UserOrder
+    /// targeting that UserState, so neither outlives the account (an old
+    /// order must not apply to a later account at the same address).
+    pub fn close_retired_user_accounts(ctx: Context<CloseRetiredUserAccounts>) -> Result<()> {
+        let core_program = ctx.accounts.governance_state.core_program;
+        let record_info = &ctx.accounts.biometric_record;
+        require_keys_eq!(
+            *record_info.owner,
+            core_program,
+            GovernanceError::UserAccountNotRetired
+        );
+        let record = CoreBiometricRecord::try_deserialize(
+            &mut &record_info.try_borrow_data()?[..]
+        )?;
+        require!(record.is_retired, GovernanceError::UserAccountNotRetired);
+        
+        let user_state = &ctx.accounts.user_state;
+        let (expected_user_state, _) = Pubkey::find_program_address(
+            &[b"user", record.user.as_ref()],
+            &core_program,
+        );
+        require_keys_eq!(
+            user_state.key(),
+            expected_user_state,
+            GovernanceError::UserAccountNotRetired
+        );
+        require!(user_state.data_is_empty(), GovernanceError::UserAccountNotRetired);
+        
+        let caller = ctx.accounts.caller.to_account_info();
+        if let Some(reimbursement) = &ctx.accounts.claim_reimbursement {
+            reimbursement.close(caller.clone())?;
+        }
+        if let Some(order) = &ctx.accounts.user_order {
+            order.close(caller)?;
+        }
+        
+        msg!("Retired user accounts closed");
+        
+        Ok(())
+    }
+}
+
+// ╔═══════════════════════════════════════════════════════════════════════════╗
+// ║                                                                           ║
+// ║                    SECTION 3: ACCOUNT STRUCTURES                          ║
+// ║                                                                           ║
+// ╚═══════════════════════════════════════════════════════════════════════════╝
+
+// ============================================================================
 // 3.1 GOVERNANCE STATE
 // ============================================================================
 
@@ -1372,10 +2715,26 @@ pub struct Proposal {
     pub created_at: i64,
     pub voting_ends_at: i64,
     pub executed_at: i64,
+    pub execution_delay: i64,
+    pub executable_after: i64,
+    pub veto_target_id: u64,
+    pub guardian_vetoes: u8,
+    pub upgrade_buffer: Pubkey,
+    pub upgrade_buffer_hash: [u8; 32],
     pub treasury_amount: u64,
     pub treasury_recipient: Pubkey,
     pub sanction_country: [u8; 3],
     pub sanction_ubi_pct: u8,
+    pub action: ProposalAction,
+    pub sanction_duration: i64,
+    pub sanction_schedule: [SanctionStep; MAX_SANCTION_STEPS],
+    pub target_account: Pubkey,
+    pub reason_code: u8,
+    pub council_members: [Pubkey; MAX_GUARDIANS],
+    pub council_veto_threshold: u8,
+    pub parameter_values: [u64; 2],
+    pub issuer_authority: Pubkey,
+    pub issuer_name: [u8; 32],
     pub bump: u8,
 }
 
@@ -1398,10 +2757,26 @@ impl Proposal {
         8 +     // created_at
         8 +     // voting_ends_at
         8 +     // executed_at
+        8 +     // execution_delay
+        8 +     // executable_after
+        8 +     // veto_target_id
+        1 +     // guardian_vetoes
+        32 +    // upgrade_buffer
+        32 +    // upgrade_buffer_hash
         8 +     // treasury_amount
         32 +    // treasury_recipient
         3 +     // sanction_country
         1 +     // sanction_ubi_pct
+        1 +     // action
+        8 +     // sanction_duration
+        SanctionStep::SIZE * MAX_SANCTION_STEPS + // sanction_schedule
+        32 +    // target_account
+        1 +     // reason_code
+        32 * MAX_GUARDIANS + // council_members
+        1 +     // council_veto_threshold
+        8 * 2 + // parameter_values
+        32 +    // issuer_authority
+        32 +    // issuer_name
         1;      // bump
 }
 
@@ -1449,6 +2824,9 @@ impl ProposalRegistry {
 /// 
 /// When Core processes a claim, it checks if a sanction exists
 /// for the user's citizenship. If so, UBI is reduced.
+/// 
+/// ⚫ Core parses this account by byte offset. Only append new fields
+/// before `bump`, never reorder existing ones.
 #[account]
 pub struct CountrySanction {
     /// ISO 3166-1 alpha-3 country code
@@ -1475,12 +2853,26 @@ pub struct CountrySanction {
     /// When sanction was lifted (if applicable)
     pub lifted_at: i64,
     
+    /// When the current terms started (imposition or last renewal).
+    /// Schedule steps are relative to this.
+    pub terms_start: i64,
+    
+    /// Number of times the sanction was renewed by a fresh vote
+    pub renewal_count: u32,
+    
+    /// Graduated schedule (unused steps have ubi_percentage = 0)
+    pub schedule: [SanctionStep; MAX_SANCTION_STEPS],
+    
+    /// Number of earlier sanctions archived in SanctionHistory
+    pub sequence: u32,
+    
     /// PDA bump
     pub bump: u8,
 }
 
 impl CountrySanction {
-    pub const SIZE: usize = 8 + 3 + 1 + 8 + 8 + 8 + 1 + 1 + 8 + 1;
+    pub const SIZE: usize = 8 + 3 + 1 + 8 + 8 + 8 + 1 + 1 + 8 + 8 + 4 +
+        SanctionStep::SIZE * MAX_SANCTION_STEPS + 4 + 1;
 }
 
 // ============================================================================
@@ -1581,6 +2973,32 @@ pub struct TrustedIssuer {
     /// Total attestations from this issuer (for statistics)
     pub total_attestations: u64,
     
+    /// Issuer's signing key - resolves fraud challenges in Core
+    /// ⚫ Core reads this at offset 113
+    pub authority: Pubkey,
+    
+    /// Until when existing attestations stay valid after removal (0 = active)
+    /// ⚫ Core reads this at offset 145
+    pub migration_deadline: i64,
+    
+    /// Maximum registrations per day accepted by Core
+    /// ⚫ Core reads this at offset 153
+    pub daily_registration_cap: u32,
+    
+    /// Maximum registrations per week accepted by Core
+    /// ⚫ Core reads this at offset 157
+    pub weekly_registration_cap: u32,
+    
+    /// When quotas were last set (lifts Core's automatic suspension)
+    /// ⚫ Core reads this at offset 161
+    pub quotas_updated_at: i64,
+    
+    /// SOL bond held in this account (lamports above rent)
+    pub bond_amount: u64,
+    
+    /// When the issuer voluntarily exited (0 = not exiting)
+    pub exit_requested_at: i64,
+    
     /// PDA bump
     pub bump: u8,
 }
@@ -1595,6 +3013,13 @@ impl TrustedIssuer {
         8 +     // deactivated_at
         8 +     // deactivation_proposal_id
         8 +     // total_attestations
+        32 +    // authority
+        8 +     // migration_deadline
+        4 +     // daily_registration_cap
+        4 +     // weekly_registration_cap
+        8 +     // quotas_updated_at
+        8 +     // bond_amount
+        8 +     // exit_requested_at
         1;      // bump
 }
 
@@ -1632,47 +3057,313 @@ impl TrustedIssuerRegistry {
         1;      // bump
 }
 
-// ╔═══════════════════════════════════════════════════════════════════════════╗
-// ║                                                                           ║
-// ║                    SECTION 4: ENUMS                                       ║
-// ║                                                                           ║
-// ╚═══════════════════════════════════════════════════════════════════════════╝
+// ============================================================================
+// 3.12 GUARDIAN COUNCIL (🔴 CONSTITUTIONAL - veto during timelock)
+// ============================================================================
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum ProposalType {
-    Standard,
-    Treasury,
-    Constitutional,
-    Sanction,
+/// Guardian council that can veto passed proposals during their timelock.
+/// 
+/// Guardians can only BLOCK execution, never execute or create anything.
+/// Membership is replaced as a whole by a Constitutional proposal.
+#[account]
+pub struct GuardianCouncil {
+    /// Guardian wallets (only the first `member_count` are valid)
+    pub members: [Pubkey; MAX_GUARDIANS],
+    
+    /// Number of active guardians
+    pub member_count: u8,
+    
+    /// Vetoes required to block a proposal (0 = guardian veto disabled)
+    pub veto_threshold: u8,
+    
+    /// Proposal that last set the council (0 = genesis)
+    pub proposal_id: u64,
+    
+    /// Last update timestamp
+    pub updated_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum ProposalStatus {
-    Active,
-    Passed,
-    Rejected,
-    Expired,
+impl GuardianCouncil {
+    pub const SIZE: usize = 8 +  // Discriminator
+        32 * MAX_GUARDIANS +    // members
+        1 +     // member_count
+        1 +     // veto_threshold
+        8 +     // proposal_id
+        8 +     // updated_at
+        1;      // bump
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum SponsorTier {
-    None,
-    Bronze,
-    Silver,
-    Gold,
-    Platinum,
-    Diamond,
+// ============================================================================
+// 3.13 GUARDIAN VETO RECORD
+// ============================================================================
+
+/// One guardian's veto on one proposal (prevents double-counting).
+#[account]
+pub struct GuardianVetoRecord {
+    pub guardian: Pubkey,
+    pub proposal_id: u64,
+    pub vetoed_at: i64,
+    pub bump: u8,
 }
 
-// ╔═══════════════════════════════════════════════════════════════════════════╗
-// ║                                                                           ║
-// ║                    SECTION 5: CONTEXT STRUCTURES                          ║
-// ║                                                                           ║
-// ╚═══════════════════════════════════════════════════════════════════════════╝
+impl GuardianVetoRecord {
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 1;
+}
 
-#[derive(Accounts)]
-pub struct InitializeGovernance<'info> {
-    #[account(mut)]
+// ============================================================================
+// 3.14 SANCTION HISTORY
+// ============================================================================
+
+/// Archived terms of a previous sanction on a country.
+/// 
+/// Written when a sanction is re-imposed after the earlier one was lifted
+/// or expired. PDA: ["sanction_history", country_code, sequence (u32 LE)]
+#[account]
+pub struct SanctionHistory {
+    pub country_code: [u8; 3],
+    pub sequence: u32,
+    pub ubi_percentage: u8,
+    pub imposed_at: i64,
+    pub expires_at: i64,
+    pub proposal_id: u64,
+    pub lifted_early: bool,
+    pub lifted_at: i64,
+    pub renewal_count: u32,
+    pub schedule: [SanctionStep; MAX_SANCTION_STEPS],
+    pub archived_at: i64,
+    pub bump: u8,
+}
+
+impl SanctionHistory {
+    pub const SIZE: usize = 8 + 3 + 4 + 1 + 8 + 8 + 8 + 1 + 8 + 4 +
+        SanctionStep::SIZE * MAX_SANCTION_STEPS + 8 + 1;
+}
+
+// ============================================================================
+// 3.15 USER ORDER (read by Core program)
+// ============================================================================
+
+/// Suspension or reinstatement order for a Core UserState.
+/// 
+/// ⚫ Core parses this account by byte offset:
+/// proposal_id @ 8, target_account @ 16, order_type @ 48, reason_code @ 49
+#[account]
+pub struct UserOrder {
+    /// Proposal that issued the order (Core applies each order once)
+    pub proposal_id: u64,
+    
+    /// Core UserState the order applies to
+    pub target_account: Pubkey,
+    
+    /// Suspend or reinstate
+    pub order_type: UserOrderType,
+    
+    /// Core suspension reason code (0 for reinstatement)
+    pub reason_code: u8,
+    
+    /// When the order was issued
+    pub issued_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl UserOrder {
+    pub const SIZE: usize = 8 + 8 + 32 + 1 + 1 + 8 + 1;
+}
+
+// ============================================================================
+// 3.16 CORE PARAMETERS (read by Core program)
+// ============================================================================
+
+/// Tunable values read by Core. PDA: ["core_parameters"]
+/// 
+/// ⚫ Core parses this account by byte offset:
+/// child_allowance_monthly_cap @ 8, dormant_closure_period @ 16
+#[account]
+pub struct CoreParameters {
+    /// Max AGORA a guardian may withdraw from a child's escrow per 30 days
+    pub child_allowance_monthly_cap: u64,
+    
+    /// Seconds a dormant Core account waits before it can be closed
+    pub dormant_closure_period: i64,
+    
+    /// Last update timestamp
+    pub last_updated: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CoreParameters {
+    pub const SIZE: usize = 8 + 8 + 8 + 8 + 1;
+}
+
+// ============================================================================
+// 3.17 CLAIM REIMBURSEMENT
+// ============================================================================
+
+/// Last delegated claim reimbursed for a Core user.
+/// PDA: ["claim_reimbursement", core_user_state]
+#[account]
+pub struct ClaimReimbursement {
+    pub user_state: Pubkey,
+    pub last_reimbursed_claim: i64,
+    pub total_reimbursed: u64,
+    pub bump: u8,
+}
+
+impl ClaimReimbursement {
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 1;
+}
+
+// ╔═══════════════════════════════════════════════════════════════════════════╗
+// ║                                                                           ║
+// ║                    SECTION 4: ENUMS                                       ║
+// ║                                                                           ║
+// ╚═══════════════════════════════════════════════════════════════════════════╝
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalType {
+    Standard,
+    Treasury,
+    Constitutional,
+    Sanction,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    Expired,
+    Cancelled,
+    Vetoed,
+    Executed,
+}
+
+/// What executing a passed proposal does.
+/// 
+/// Execution instructions check the action so that a proposal can only
+/// be used for what voters actually approved.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalAction {
+    None,
+    ImposeSanction,
+    RenewSanction,
+    LiftSanction,
+    SuspendUser,
+    ReinstateUser,
+    SlashIssuer,
+    SetGuardianCouncil,
+    UpdateFeeParameters,
+    ProgramUpgrade,
+    AddTrustedIssuer,
+    RemoveTrustedIssuer,
+    SetIssuerQuotas,
+    SetChildAllowanceCap,
+    SetDormantClosurePeriod,
+}
+
+/// Order type carried by a UserOrder (Core reads the variant byte).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum UserOrderType {
+    Suspend,
+    Reinstate,
+}
+
+/// One step of a graduated sanction schedule.
+/// 
+/// From `terms_start + starts_after` onwards the sanction applies
+/// `ubi_percentage` instead of the base percentage (or earlier steps).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SanctionStep {
+    /// Seconds after the terms started when this step takes effect
+    pub starts_after: i64,
+    
+    /// UBI percentage from this step on (0 = unused step)
+    pub ubi_percentage: u8,
+}
+
+impl SanctionStep {
+    pub const SIZE: usize = 8 + 1;
+}
+
+/// What a proposal commits to, passed to `create_proposal`.
+/// 
+/// Stored on the Proposal and validated per action; executors read these
+/// values from the proposal, never from their own arguments. Fields an
+/// action does not use are left zeroed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalTerms {
+    /// What executing the proposal does (e.g. ImposeSanction)
+    pub action: ProposalAction,
+    
+    /// Proposal this one vetoes if passed (0 = none)
+    pub veto_target_id: u64,
+    
+    /// Program buffer to deploy (ProgramUpgrade only, default = none)
+    pub upgrade_buffer: Pubkey,
+    
+    /// SHA-256 of the buffer's program bytes
+    pub upgrade_buffer_hash: [u8; 32],
+    
+    /// Sanction length in seconds (0 = default, max 365 days)
+    pub sanction_duration: i64,
+    
+    /// Optional graduated sanction steps (unused steps are zeroed)
+    pub sanction_schedule: [SanctionStep; MAX_SANCTION_STEPS],
+    
+    /// Core UserState (SuspendUser / ReinstateUser), TrustedIssuer
+    /// (SlashIssuer / RemoveTrustedIssuer / SetIssuerQuotas) or SAS
+    /// credential (AddTrustedIssuer)
+    pub target_account: Pubkey,
+    
+    /// Core suspension reason code (SuspendUser only)
+    pub reason_code: u8,
+    
+    /// New guardian council (SetGuardianCouncil only; members first,
+    /// unused slots zeroed)
+    pub council_members: [Pubkey; MAX_GUARDIANS],
+    
+    /// Vetoes needed by the new council
+    pub council_veto_threshold: u8,
+    
+    /// New values for parameter actions (UpdateFeeParameters: base rate,
+    /// burn percentage; SetIssuerQuotas: daily cap, weekly cap;
+    /// SetChildAllowanceCap: monthly cap; SetDormantClosurePeriod: period
+    /// in seconds)
+    pub parameter_values: [u64; 2],
+    
+    /// Issuer signing key (AddTrustedIssuer only)
+    pub issuer_authority: Pubkey,
+    
+    /// Issuer name (AddTrustedIssuer only)
+    pub issuer_name: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum SponsorTier {
+    None,
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Diamond,
+}
+
+// ╔═══════════════════════════════════════════════════════════════════════════╗
+// ║                                                                           ║
+// ║                    SECTION 5: CONTEXT STRUCTURES                          ║
+// ║                                                                           ║
+// ╚═══════════════════════════════════════════════════════════════════════════╝
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
     
     #[account(
@@ -1763,11 +3454,33 @@ pub struct CreateProposal<'info> {
 
 #[derive(Accounts)]
 pub struct VoteOnProposal<'info> {
+    #[account(mut)]
     pub voter: Signer<'info>,
     
-    /// CHECK: Voter's Core UserState
+    /// CHECK: Voter's Core UserState - PDA verified, parsed manually
+    #[account(
+        seeds = [b"user", voter.key().as_ref()],
+        bump,
+        seeds::program = governance_state.core_program
+    )]
     pub voter_state: AccountInfo<'info>,
     
+    #[account(
+        seeds = [b"governance"],
+        bump = governance_state.bump
+    )]
+    pub governance_state: Account<'info, GovernanceState>,
+    
+    /// CHECK: Voter's SAS attestation - must match Core UserState, parsed manually
+    pub voter_attestation: AccountInfo<'info>,
+    
+    /// Trusted issuer of the voter's attestation
+    #[account(
+        seeds = [b"trusted_issuer", voter_issuer.credential.as_ref()],
+        bump = voter_issuer.bump
+    )]
+    pub voter_issuer: Account<'info, TrustedIssuer>,
+    
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
     
@@ -1816,11 +3529,47 @@ pub struct FinalizeProposal<'info> {
     pub token_program: Program<'info, anchor_spl::token::Token>,
 }
 
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = proposal.proposer == proposer.key() @ GovernanceError::NotProposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        seeds = [b"governance"],
+        bump = governance_state.bump
+    )]
+    pub governance_state: Account<'info, GovernanceState>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal_registry"],
+        bump = proposal_registry.bump
+    )]
+    pub proposal_registry: Account<'info, ProposalRegistry>,
+    
+    #[account(mut)]
+    pub bond_escrow: Account<'info, anchor_spl::token::TokenAccount>,
+    
+    #[account(mut)]
+    pub proposer_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+    
+    #[account(mut)]
+    pub treasury_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+    
+    pub token_program: Program<'info, anchor_spl::token::Token>,
+}
+
 #[derive(Accounts)]
 pub struct ImposeSanction<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    #[account(mut)]
     pub proposal: Account<'info, Proposal>,
     
     #[account(
@@ -1836,10 +3585,59 @@ pub struct ImposeSanction<'info> {
 }
 
 #[derive(Accounts)]
-pub struct LiftSanction<'info> {
+pub struct ReimposeSanction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"sanction", proposal.sanction_country.as_ref()],
+        bump = country_sanction.bump
+    )]
+    pub country_sanction: Account<'info, CountrySanction>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = SanctionHistory::SIZE,
+        seeds = [
+            b"sanction_history",
+            proposal.sanction_country.as_ref(),
+            &country_sanction.sequence.to_le_bytes()
+        ],
+        bump
+    )]
+    pub sanction_history: Account<'info, SanctionHistory>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RenewSanction<'info> {
     #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"sanction", country_sanction.country_code.as_ref()],
+        bump = country_sanction.bump
+    )]
+    pub country_sanction: Account<'info, CountrySanction>,
+}
+
+#[derive(Accounts)]
+pub struct LiftSanction<'info> {
+    #[account(
+        mut,
+        seeds = [b"sanction", country_sanction.country_code.as_ref()],
+        bump = country_sanction.bump
+    )]
     pub country_sanction: Account<'info, CountrySanction>,
     
+    #[account(mut)]
     pub lift_proposal: Account<'info, Proposal>,
 }
 
@@ -1850,182 +3648,788 @@ pub struct InitializeGasPool<'info> {
     
     #[account(
         init,
-        payer = authority,
-        space = GasPoolState::SIZE,
-        seeds = [b"gas_pool"],
+        payer = authority,
+        space = GasPoolState::SIZE,
+        seeds = [b"gas_pool"],
+        bump
+    )]
+    pub gas_pool_state: Account<'info, GasPoolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SponsorGasPool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"gas_pool"],
+        bump = gas_pool_state.bump
+    )]
+    pub gas_pool_state: Account<'info, GasPoolState>,
+    
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = SponsorRecord::SIZE,
+        seeds = [b"sponsor", sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsor_record: Account<'info, SponsorRecord>,
+    
+    /// CHECK: Gas pool vault PDA
+    #[account(mut)]
+    pub gas_pool_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReimburseClaimDelegate<'info> {
+    /// Delegate that claimed on the user's behalf
+    #[account(mut)]
+    pub delegate: Signer<'info>,
+    
+    #[account(
+        seeds = [b"governance"],
+        bump = governance_state.bump
+    )]
+    pub governance_state: Account<'info, GovernanceState>,
+    
+    /// Wallet of the user claimed for
+    /// CHECK: Used only for PDA derivation
+    pub user: AccountInfo<'info>,
+    
+    /// Core UserState of the user claimed for
+    /// CHECK: Seeds verified against Core; parsed manually
+    #[account(
+        seeds = [b"user", user.key().as_ref()],
+        bump,
+        seeds::program = governance_state.core_program,
+        constraint = *user_state.owner == governance_state.core_program @ GovernanceError::InvalidClaimReimbursement
+    )]
+    pub user_state: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = delegate,
+        space = ClaimReimbursement::SIZE,
+        seeds = [b"claim_reimbursement", user_state.key().as_ref()],
+        bump
+    )]
+    pub claim_reimbursement: Account<'info, ClaimReimbursement>,
+    
+    #[account(
+        mut,
+        seeds = [b"gas_pool"],
+        bump = gas_pool_state.bump
+    )]
+    pub gas_pool_state: Account<'info, GasPoolState>,
+    
+    /// CHECK: Gas pool vault PDA (system-owned, holds pool SOL)
+    #[account(
+        mut,
+        seeds = [b"gas_pool_vault"],
+        bump
+    )]
+    pub gas_pool_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeeParameters<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_state"],
+        bump = fee_state.bump
+    )]
+    pub fee_state: Account<'info, FeeState>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+// ╔═══════════════════════════════════════════════════════════════════════════╗
+// ║                                                                           ║
+// ║                    SECTION 6: HELPER FUNCTIONS                            ║
+// ║                                                                           ║
+// ╚═══════════════════════════════════════════════════════════════════════════╝
+
+fn calculate_sponsor_tier(total_contribution: u64) -> SponsorTier {
+    if total_contribution >= DIAMOND_THRESHOLD {
+        SponsorTier::Diamond
+    } else if total_contribution >= PLATINUM_THRESHOLD {
+        SponsorTier::Platinum
+    } else if total_contribution >= GOLD_THRESHOLD {
+        SponsorTier::Gold
+    } else if total_contribution >= SILVER_THRESHOLD {
+        SponsorTier::Silver
+    } else if total_contribution >= BRONZE_THRESHOLD {
+        SponsorTier::Bronze
+    } else {
+        SponsorTier::None
+    }
+}
+
+fn get_personal_allocation_pct(tier: &SponsorTier) -> u64 {
+    match tier {
+        SponsorTier::None => 0,
+        SponsorTier::Bronze => BRONZE_PERSONAL_PCT,
+        SponsorTier::Silver => SILVER_PERSONAL_PCT,
+        SponsorTier::Gold => GOLD_PERSONAL_PCT,
+        SponsorTier::Platinum => PLATINUM_PERSONAL_PCT,
+        SponsorTier::Diamond => DIAMOND_PERSONAL_PCT,
+    }
+}
+
+/// Verify a proposal has passed, has not been executed or vetoed, and its
+/// execution timelock has elapsed.
+fn require_executable(proposal: &Proposal, current_time: i64) -> Result<()> {
+    require!(
+        proposal.status != ProposalStatus::Executed,
+        GovernanceError::ProposalAlreadyExecuted
+    );
+    require!(
+        proposal.status != ProposalStatus::Vetoed,
+        GovernanceError::ProposalWasVetoed
+    );
+    require!(
+        proposal.status == ProposalStatus::Passed,
+        GovernanceError::ProposalNotPassed
+    );
+    require!(
+        current_time >= proposal.executable_after,
+        GovernanceError::TimelockActive
+    );
+    Ok(())
+}
+
+/// Verify a proposal is passed and still inside its veto window.
+fn require_veto_window(proposal: &Proposal, current_time: i64) -> Result<()> {
+    require!(
+        proposal.status == ProposalStatus::Passed,
+        GovernanceError::ProposalNotPassed
+    );
+    require!(
+        current_time < proposal.executable_after,
+        GovernanceError::VetoWindowClosed
+    );
+    Ok(())
+}
+
+/// Verify `user_state` is the Core UserState of `wallet` and not suspended.
+/// 
+/// Offsets come from Core so the two layouts cannot drift apart.
+fn require_verified_core_user(
+    user_state: &AccountInfo,
+    wallet: &Pubkey,
+    core_program: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(*user_state.owner, *core_program, GovernanceError::VoterNotVerified);
+    
+    let data = user_state.try_borrow_data()?;
+    require!(
+        data.len() > CoreUserState::IS_VERIFIED_OFFSET,
+        GovernanceError::VoterNotVerified
+    );
+    
+    let owner = Pubkey::new_from_array(
+        data[CoreUserState::OWNER_OFFSET..CoreUserState::OWNER_OFFSET + 32].try_into().unwrap()
+    );
+    require_keys_eq!(owner, *wallet, GovernanceError::VoterNotVerified);
+    require!(
+        data[CoreUserState::IS_VERIFIED_OFFSET] == 1,
+        GovernanceError::VoterNotVerified
+    );
+    
+    Ok(())
+}
+
+/// Verify the SAS attestation recorded in a Core UserState is still valid.
+/// 
+/// UserState and SAS attestation offsets are shared with Core.
+fn require_valid_attestation(
+    user_state: &AccountInfo,
+    attestation: &AccountInfo,
+    issuer: &TrustedIssuer,
+    wallet: &Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    let user_data = user_state.try_borrow_data()?;
+    const ATTESTATION: usize = CoreUserState::ATTESTATION_OFFSET;
+    require!(user_data.len() >= ATTESTATION + 32, GovernanceError::VoterNotVerified);
+    let recorded = Pubkey::new_from_array(
+        user_data[ATTESTATION..ATTESTATION + 32].try_into().unwrap()
+    );
+    require_keys_eq!(attestation.key(), recorded, GovernanceError::AttestationInvalid);
+    require_keys_eq!(*attestation.owner, SAS_PROGRAM_ID, GovernanceError::AttestationInvalid);
+    
+    let data = attestation.try_borrow_data()?;
+    require!(data.len() >= SAS_DATA_OFFSET, GovernanceError::AttestationInvalid);
+    
+    let credential = Pubkey::new_from_array(
+        data[SAS_CREDENTIAL_OFFSET..SAS_CREDENTIAL_OFFSET + 32].try_into().unwrap()
+    );
+    let nonce = Pubkey::new_from_array(
+        data[SAS_NONCE_OFFSET..SAS_NONCE_OFFSET + 32].try_into().unwrap()
+    );
+    let expiry = i64::from_le_bytes(
+        data[SAS_EXPIRY_OFFSET..SAS_EXPIRY_OFFSET + 8].try_into().unwrap()
+    );
+    
+    require_keys_eq!(credential, issuer.credential, GovernanceError::AttestationInvalid);
+    require!(
+        issuer.is_active || current_time < issuer.migration_deadline,
+        GovernanceError::AttestationInvalid
+    );
+    require_keys_eq!(nonce, *wallet, GovernanceError::AttestationInvalid);
+    require!(expiry == 0 || expiry > current_time, GovernanceError::AttestationInvalid);
+    require!(data[SAS_REVOKED_OFFSET] == 0, GovernanceError::AttestationInvalid);
+    
+    Ok(())
+}
+
+/// Validate a guardian council committed in a proposal.
+/// 
+/// Members fill the first slots (unused slots zeroed) and are unique.
+/// An empty council (threshold 0) disables guardian vetoes entirely.
+/// Returns the member count.
+fn validate_guardian_council(
+    members: &[Pubkey; MAX_GUARDIANS],
+    veto_threshold: u8,
+) -> Result<u8> {
+    let member_count = members
+        .iter()
+        .position(|m| *m == Pubkey::default())
+        .unwrap_or(MAX_GUARDIANS);
+    
+    for (i, member) in members.iter().enumerate() {
+        if i < member_count {
+            require!(
+                !members[..i].contains(member),
+                GovernanceError::InvalidGuardianCouncil
+            );
+        } else {
+            require!(
+                *member == Pubkey::default(),
+                GovernanceError::InvalidGuardianCouncil
+            );
+        }
+    }
+    require!(
+        veto_threshold as usize <= member_count
+            && (member_count == 0 || veto_threshold > 0),
+        GovernanceError::InvalidGuardianCouncil
+    );
+    
+    Ok(member_count as u8)
+}
+
+/// Validate sanction terms from a proposal.
+/// 
+/// Returns the effective duration (`DEFAULT_SANCTION_DURATION` if 0 was given).
+fn validate_sanction_terms(
+    ubi_pct: u8,
+    duration: i64,
+    schedule: &[SanctionStep; MAX_SANCTION_STEPS],
+) -> Result<i64> {
+    require!(
+        (MIN_SANCTION_UBI_PCT..=99).contains(&ubi_pct),
+        GovernanceError::InvalidSanctionPercentage
+    );
+    
+    let duration = if duration == 0 { DEFAULT_SANCTION_DURATION } else { duration };
+    require!(
+        duration > 0 && duration <= MAX_SANCTION_DURATION,
+        GovernanceError::InvalidSanctionDuration
+    );
+    
+    // Used steps come first, start strictly later than the previous one
+    // and inside the term. Unused steps must be fully zeroed.
+    let mut previous_start = 0;
+    let mut schedule_ended = false;
+    for step in schedule.iter() {
+        if step.ubi_percentage == 0 {
+            require!(step.starts_after == 0, GovernanceError::InvalidSanctionSchedule);
+            schedule_ended = true;
+            continue;
+        }
+        require!(!schedule_ended, GovernanceError::InvalidSanctionSchedule);
+        require!(
+            step.ubi_percentage >= MIN_SANCTION_UBI_PCT && step.ubi_percentage <= 99,
+            GovernanceError::InvalidSanctionPercentage
+        );
+        require!(
+            step.starts_after > previous_start && step.starts_after < duration,
+            GovernanceError::InvalidSanctionSchedule
+        );
+        previous_start = step.starts_after;
+    }
+    
+    Ok(duration)
+}
+
+/// Copy the terms voted in `proposal` onto `sanction`, starting now.
+fn apply_sanction_terms(sanction: &mut CountrySanction, proposal: &Proposal, current_time: i64) {
+    sanction.ubi_percentage = proposal.sanction_ubi_pct;
+    sanction.terms_start = current_time;
+    sanction.expires_at = current_time + proposal.sanction_duration;
+    sanction.schedule = proposal.sanction_schedule;
+    sanction.proposal_id = proposal.id;
+}
+
+// ============================================================================
+// TRUSTED ISSUER CONTEXT STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(civic_credential: Pubkey)]
+pub struct InitializeIssuerRegistry<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = TrustedIssuerRegistry::SIZE,
+        seeds = [b"issuer_registry"],
+        bump
+    )]
+    pub issuer_registry: Account<'info, TrustedIssuerRegistry>,
+    
+    /// Civic as the initial trusted issuer (same PDA as any other issuer,
+    /// so it can be slashed, exit, and have its quotas set)
+    #[account(
+        init,
+        payer = authority,
+        space = TrustedIssuer::SIZE,
+        seeds = [b"trusted_issuer", civic_credential.as_ref()],
+        bump
+    )]
+    pub civic_issuer: Account<'info, TrustedIssuer>,
+    
+    /// Civic's signing key - posts the genesis issuer bond
+    #[account(mut)]
+    pub civic_authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddTrustedIssuer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Issuer's signing key - posts the bond (committed in the proposal)
+    #[account(
+        mut,
+        address = proposal.issuer_authority @ GovernanceError::InvalidIssuerBond
+    )]
+    pub issuer_authority: Signer<'info>,
+    
+    /// Passed AddTrustedIssuer proposal
+    #[account(
+        mut,
+        constraint = proposal.status == ProposalStatus::Passed @ GovernanceError::ProposalNotPassed,
+        constraint = proposal.proposal_type == ProposalType::Constitutional @ GovernanceError::WrongProposalType
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    /// New trusted issuer account (for the credential in the proposal)
+    #[account(
+        init,
+        payer = authority,
+        space = TrustedIssuer::SIZE,
+        seeds = [b"trusted_issuer", proposal.target_account.as_ref()],
+        bump
+    )]
+    pub trusted_issuer: Account<'info, TrustedIssuer>,
+    
+    /// Issuer registry
+    #[account(
+        mut,
+        seeds = [b"issuer_registry"],
+        bump = issuer_registry.bump
+    )]
+    pub issuer_registry: Account<'info, TrustedIssuerRegistry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveTrustedIssuer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Passed Constitutional proposal
+    #[account(
+        mut,
+        constraint = proposal.status == ProposalStatus::Passed @ GovernanceError::ProposalNotPassed,
+        constraint = proposal.proposal_type == ProposalType::Constitutional @ GovernanceError::WrongProposalType
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    /// Trusted issuer to deactivate (committed in the proposal)
+    #[account(
+        mut,
+        seeds = [b"trusted_issuer", trusted_issuer.credential.as_ref()],
+        bump = trusted_issuer.bump,
+        address = proposal.target_account @ GovernanceError::IssuerNotActive,
+        constraint = trusted_issuer.is_active @ GovernanceError::IssuerNotActive
+    )]
+    pub trusted_issuer: Account<'info, TrustedIssuer>,
+    
+    /// Issuer registry
+    #[account(
+        mut,
+        seeds = [b"issuer_registry"],
+        bump = issuer_registry.bump
+    )]
+    pub issuer_registry: Account<'info, TrustedIssuerRegistry>,
+}
+
+#[derive(Accounts)]
+pub struct SetIssuerQuotas<'info> {
+    pub authority: Signer<'info>,
+    
+    /// Passed Constitutional proposal
+    #[account(
+        mut,
+        constraint = proposal.status == ProposalStatus::Passed @ GovernanceError::ProposalNotPassed,
+        constraint = proposal.proposal_type == ProposalType::Constitutional @ GovernanceError::WrongProposalType
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    /// Issuer whose quotas are set (committed in the proposal)
+    #[account(
+        mut,
+        seeds = [b"trusted_issuer", trusted_issuer.credential.as_ref()],
+        bump = trusted_issuer.bump,
+        address = proposal.target_account @ GovernanceError::InvalidIssuerQuota
+    )]
+    pub trusted_issuer: Account<'info, TrustedIssuer>,
+}
+
+#[derive(Accounts)]
+pub struct SlashIssuerBond<'info> {
+    pub authority: Signer<'info>,
+    
+    /// Passed SlashIssuer proposal
+    #[account(
+        mut,
+        constraint = proposal.status == ProposalStatus::Passed @ GovernanceError::ProposalNotPassed
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"trusted_issuer", trusted_issuer.credential.as_ref()],
+        bump = trusted_issuer.bump,
+        address = proposal.target_account @ GovernanceError::InvalidIssuerBond
+    )]
+    pub trusted_issuer: Account<'info, TrustedIssuer>,
+    
+    /// CHECK: Must match the proposal's treasury_recipient
+    #[account(
+        mut,
+        address = proposal.treasury_recipient @ GovernanceError::InvalidIssuerBond
+    )]
+    pub recipient: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct IssuerExit<'info> {
+    pub issuer_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"trusted_issuer", trusted_issuer.credential.as_ref()],
+        bump = trusted_issuer.bump,
+        constraint = trusted_issuer.authority == issuer_authority.key() @ GovernanceError::InvalidIssuerBond
+    )]
+    pub trusted_issuer: Account<'info, TrustedIssuer>,
+    
+    #[account(
+        mut,
+        seeds = [b"issuer_registry"],
+        bump = issuer_registry.bump
+    )]
+    pub issuer_registry: Account<'info, TrustedIssuerRegistry>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawIssuerBond<'info> {
+    #[account(mut)]
+    pub issuer_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"trusted_issuer", trusted_issuer.credential.as_ref()],
+        bump = trusted_issuer.bump,
+        constraint = trusted_issuer.authority == issuer_authority.key() @ GovernanceError::InvalidIssuerBond
+    )]
+    pub trusted_issuer: Account<'info, TrustedIssuer>,
+}
+
+#[derive(Accounts)]
+pub struct SyncIssuerStats<'info> {
+    #[account(
+        seeds = [b"governance"],
+        bump = governance_state.bump
+    )]
+    pub governance_state: Account<'info, GovernanceState>,
+    
+    #[account(
+        mut,
+        seeds = [b"trusted_issuer", trusted_issuer.credential.as_ref()],
+        bump = trusted_issuer.bump,
+    )]
+    pub trusted_issuer: Account<'info, TrustedIssuer>,
+    
+    /// Core IssuerStats PDA for this issuer
+    /// CHECK: Owner and seeds verified against Core; parsed manually
+    #[account(
+        seeds = [b"issuer_stats", trusted_issuer.credential.as_ref()],
+        bump,
+        seeds::program = governance_state.core_program,
+    )]
+    pub issuer_stats: AccountInfo<'info>,
+}
+
+// ============================================================================
+// TIMELOCK & VETO CONTEXT STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeGuardianCouncil<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = GuardianCouncil::SIZE,
+        seeds = [b"guardian_council"],
+        bump
+    )]
+    pub guardian_council: Account<'info, GuardianCouncil>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetGuardianCouncil<'info> {
+    /// Passed SetGuardianCouncil proposal
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"guardian_council"],
+        bump = guardian_council.bump
+    )]
+    pub guardian_council: Account<'info, GuardianCouncil>,
+}
+
+#[derive(Accounts)]
+pub struct GuardianVeto<'info> {
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        seeds = [b"guardian_council"],
+        bump = guardian_council.bump
+    )]
+    pub guardian_council: Account<'info, GuardianCouncil>,
+    
+    #[account(
+        init,
+        payer = guardian,
+        space = GuardianVetoRecord::SIZE,
+        seeds = [b"guardian_veto", proposal.key().as_ref(), guardian.key().as_ref()],
         bump
     )]
-    pub gas_pool_state: Account<'info, GasPoolState>,
+    pub veto_record: Account<'info, GuardianVetoRecord>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct SponsorGasPool<'info> {
+pub struct VetoByCounterProposal<'info> {
+    /// Passed proposal to veto (must be inside its timelock)
     #[account(mut)]
-    pub sponsor: Signer<'info>,
+    pub proposal: Account<'info, Proposal>,
     
+    /// Passed counter-proposal naming `proposal` as its veto target
     #[account(
         mut,
-        seeds = [b"gas_pool"],
-        bump = gas_pool_state.bump
+        constraint = counter_proposal.key() != proposal.key() @ GovernanceError::InvalidVetoTarget
     )]
-    pub gas_pool_state: Account<'info, GasPoolState>,
+    pub counter_proposal: Account<'info, Proposal>,
+}
+
+// ============================================================================
+// PROGRAM UPGRADE CONTEXT STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ExecuteProgramUpgrade<'info> {
+    pub executor: Signer<'info>,
+    
+    /// Passed Constitutional proposal committing to the buffer
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
     
+    /// CHECK: PDA holding this program's upgrade authority (no data)
     #[account(
-        init_if_needed,
-        payer = sponsor,
-        space = SponsorRecord::SIZE,
-        seeds = [b"sponsor", sponsor.key().as_ref()],
+        seeds = [b"upgrade_authority"],
         bump
     )]
-    pub sponsor_record: Account<'info, SponsorRecord>,
+    pub upgrade_authority: AccountInfo<'info>,
     
-    /// CHECK: Gas pool vault PDA
-    #[account(mut)]
-    pub gas_pool_vault: AccountInfo<'info>,
+    /// CHECK: This program's own program account
+    #[account(
+        mut,
+        address = crate::ID
+    )]
+    pub program_account: AccountInfo<'info>,
     
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct UpdateFeeParameters<'info> {
+    /// CHECK: This program's ProgramData account, derived under the loader
     #[account(
         mut,
-        seeds = [b"fee_state"],
-        bump = fee_state.bump
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID
     )]
-    pub fee_state: Account<'info, FeeState>,
+    pub program_data: AccountInfo<'info>,
     
-    pub proposal: Account<'info, Proposal>,
-}
-
-// ╔═══════════════════════════════════════════════════════════════════════════╗
-// ║                                                                           ║
-// ║                    SECTION 6: HELPER FUNCTIONS                            ║
-// ║                                                                           ║
-// ╚═══════════════════════════════════════════════════════════════════════════╝
-
-fn calculate_sponsor_tier(total_contribution: u64) -> SponsorTier {
-    if total_contribution >= DIAMOND_THRESHOLD {
-        SponsorTier::Diamond
-    } else if total_contribution >= PLATINUM_THRESHOLD {
-        SponsorTier::Platinum
-    } else if total_contribution >= GOLD_THRESHOLD {
-        SponsorTier::Gold
-    } else if total_contribution >= SILVER_THRESHOLD {
-        SponsorTier::Silver
-    } else if total_contribution >= BRONZE_THRESHOLD {
-        SponsorTier::Bronze
-    } else {
-        SponsorTier::None
-    }
-}
-
-fn get_personal_allocation_pct(tier: &SponsorTier) -> u64 {
-    match tier {
-        SponsorTier::None => 0,
-        SponsorTier::Bronze => BRONZE_PERSONAL_PCT,
-        SponsorTier::Silver => SILVER_PERSONAL_PCT,
-        SponsorTier::Gold => GOLD_PERSONAL_PCT,
-        SponsorTier::Platinum => PLATINUM_PERSONAL_PCT,
-        SponsorTier::Diamond => DIAMOND_PERSONAL_PCT,
-    }
+    /// CHECK: Verified against proposal.upgrade_buffer and its hash
+    #[account(
+        mut,
+        address = proposal.upgrade_buffer @ GovernanceError::UpgradeBufferMismatch
+    )]
+    pub buffer: AccountInfo<'info>,
+    
+    /// CHECK: Receives the buffer's lamports after the upgrade (the proposer)
+    #[account(
+        mut,
+        address = proposal.proposer @ GovernanceError::InvalidSpillAccount
+    )]
+    pub spill: AccountInfo<'info>,
+    
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+    
+    /// CHECK: BPF upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable: AccountInfo<'info>,
 }
 
 // ============================================================================
-// TRUSTED ISSUER CONTEXT STRUCTURES
+// USER DUE PROCESS CONTEXT STRUCTURES
 // ============================================================================
 
 #[derive(Accounts)]
-pub struct InitializeIssuerRegistry<'info> {
+pub struct IssueUserOrder<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub executor: Signer<'info>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
     
     #[account(
         init,
-        payer = authority,
-        space = TrustedIssuerRegistry::SIZE,
-        seeds = [b"issuer_registry"],
+        payer = executor,
+        space = UserOrder::SIZE,
+        seeds = [b"user_order", proposal.id.to_le_bytes().as_ref()],
         bump
     )]
-    pub issuer_registry: Account<'info, TrustedIssuerRegistry>,
+    pub user_order: Account<'info, UserOrder>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// CORE PARAMETERS CONTEXT STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeCoreParameters<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
     
-    /// Civic as the initial trusted issuer
     #[account(
         init,
         payer = authority,
-        space = TrustedIssuer::SIZE,
-        seeds = [b"trusted_issuer", b"civic"],
+        space = CoreParameters::SIZE,
+        seeds = [b"core_parameters"],
         bump
     )]
-    pub civic_issuer: Account<'info, TrustedIssuer>,
+    pub core_parameters: Account<'info, CoreParameters>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(credential: Pubkey, name: [u8; 32])]
-pub struct AddTrustedIssuer<'info> {
-    #[account(mut)]
+pub struct SetCoreParameter<'info> {
     pub authority: Signer<'info>,
     
     /// Passed Constitutional proposal
     #[account(
+        mut,
         constraint = proposal.status == ProposalStatus::Passed @ GovernanceError::ProposalNotPassed,
         constraint = proposal.proposal_type == ProposalType::Constitutional @ GovernanceError::WrongProposalType
     )]
     pub proposal: Account<'info, Proposal>,
     
-    /// New trusted issuer account
-    #[account(
-        init,
-        payer = authority,
-        space = TrustedIssuer::SIZE,
-        seeds = [b"trusted_issuer", credential.as_ref()],
-        bump
-    )]
-    pub trusted_issuer: Account<'info, TrustedIssuer>,
-    
-    /// Issuer registry
     #[account(
         mut,
-        seeds = [b"issuer_registry"],
-        bump = issuer_registry.bump
+        seeds = [b"core_parameters"],
+        bump = core_parameters.bump
     )]
-    pub issuer_registry: Account<'info, TrustedIssuerRegistry>,
-    
-    pub system_program: Program<'info, System>,
+    pub core_parameters: Account<'info, CoreParameters>,
 }
 
 #[derive(Accounts)]
-pub struct RemoveTrustedIssuer<'info> {
+pub struct CloseRetiredUserAccounts<'info> {
+    /// Anyone can clean up (receives the rent)
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub caller: Signer<'info>,
     
-    /// Passed Constitutional proposal
     #[account(
-        constraint = proposal.status == ProposalStatus::Passed @ GovernanceError::ProposalNotPassed,
-        constraint = proposal.proposal_type == ProposalType::Constitutional @ GovernanceError::WrongProposalType
+        seeds = [b"governance"],
+        bump = governance_state.bump
     )]
-    pub proposal: Account<'info, Proposal>,
+    pub governance_state: Account<'info, GovernanceState>,
+    
+    /// Core BiometricRecord of the user (must be retired)
+    /// CHECK: Owner verified against Core; parsed manually
+    pub biometric_record: AccountInfo<'info>,
+    
+    /// The user's closed Core UserState
+    /// CHECK: Address derived from the biometric record's user; must be empty
+    pub user_state: AccountInfo<'info>,
     
-    /// Trusted issuer to deactivate
     #[account(
         mut,
-        seeds = [b"trusted_issuer", trusted_issuer.credential.as_ref()],
-        bump = trusted_issuer.bump,
-        constraint = trusted_issuer.is_active @ GovernanceError::IssuerNotActive
+        seeds = [b"claim_reimbursement", user_state.key().as_ref()],
+        bump = claim_reimbursement.bump
     )]
-    pub trusted_issuer: Account<'info, TrustedIssuer>,
+    pub claim_reimbursement: Option<Account<'info, ClaimReimbursement>>,
     
-    /// Issuer registry
     #[account(
         mut,
-        seeds = [b"issuer_registry"],
-        bump = issuer_registry.bump
+        seeds = [b"user_order", user_order.proposal_id.to_le_bytes().as_ref()],
+        bump = user_order.bump,
+        constraint = user_order.target_account == user_state.key() @ GovernanceError::UserAccountNotRetired
     )]
-    pub issuer_registry: Account<'info, TrustedIssuerRegistry>,
+    pub user_order: Option<Account<'info, UserOrder>>,
 }
 
 // ╔═══════════════════════════════════════════════════════════════════════════╗
@@ -2072,6 +4476,16 @@ pub struct ProposalFinalized {
     pub approved: bool,
     pub reputation_change: i32,
     pub bond_returned: bool,
+    pub executable_after: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub bond_returned: u64,
+    pub bond_forfeited: u64,
     pub timestamp: i64,
 }
 
@@ -2084,6 +4498,16 @@ pub struct SanctionImposed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct SanctionRenewed {
+    pub country_code: [u8; 3],
+    pub ubi_percentage: u8,
+    pub proposal_id: u64,
+    pub renewal_count: u32,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct SanctionLifted {
     pub country_code: [u8; 3],
@@ -2127,6 +4551,7 @@ pub struct FeeParametersUpdated {
 pub struct TrustedIssuerAdded {
     pub credential: Pubkey,
     pub name: [u8; 32],
+    pub authority: Pubkey,
     pub proposal_id: u64,
     pub timestamp: i64,
 }
@@ -2135,6 +4560,68 @@ pub struct TrustedIssuerAdded {
 pub struct TrustedIssuerRemoved {
     pub credential: Pubkey,
     pub proposal_id: u64,
+    pub migration_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerQuotasUpdated {
+    pub credential: Pubkey,
+    pub daily_cap: u32,
+    pub weekly_cap: u32,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimDelegateReimbursed {
+    pub user_state: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChildAllowanceCapUpdated {
+    pub monthly_cap: u64,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DormantClosurePeriodUpdated {
+    pub closure_period: i64,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerBondSlashed {
+    pub credential: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerExitRequested {
+    pub credential: Pubkey,
+    pub withdrawable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerBondWithdrawn {
+    pub credential: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerStatsSynced {
+    pub credential: Pubkey,
+    pub total_attestations: u64,
     pub timestamp: i64,
 }
 
@@ -2144,6 +4631,48 @@ pub struct IssuerRegistryInitialized {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct GuardianCouncilUpdated {
+    pub member_count: u8,
+    pub veto_threshold: u8,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianVetoCast {
+    pub proposal_id: u64,
+    pub guardian: Pubkey,
+    pub vetoes: u8,
+    pub veto_threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalVetoed {
+    pub id: u64,
+    pub by_guardians: bool,
+    pub counter_proposal_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserOrderIssued {
+    pub proposal_id: u64,
+    pub target_account: Pubkey,
+    pub suspend: bool,
+    pub reason_code: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProgramUpgraded {
+    pub proposal_id: u64,
+    pub buffer: Pubkey,
+    pub buffer_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
 // ╔═══════════════════════════════════════════════════════════════════════════╗
 // ║                                                                           ║
 // ║                    SECTION 8: ERROR CODES                                 ║
@@ -2199,6 +4728,93 @@ pub enum GovernanceError {
     
     #[msg("Issuer already exists")]
     IssuerAlreadyExists,
+    
+    #[msg("Only the proposer can do this")]
+    NotProposer,
+    
+    #[msg("Proposal can no longer be cancelled (time limit or vote threshold reached)")]
+    CancellationWindowClosed,
+    
+    #[msg("Execution timelock has not elapsed yet")]
+    TimelockActive,
+    
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    
+    #[msg("Proposal was vetoed and cannot be executed")]
+    ProposalWasVetoed,
+    
+    #[msg("Veto window has closed")]
+    VetoWindowClosed,
+    
+    #[msg("Signer is not a member of the guardian council")]
+    NotGuardian,
+    
+    #[msg("Invalid guardian council (too many members, duplicates, or bad threshold)")]
+    InvalidGuardianCouncil,
+    
+    #[msg("Counter-proposal does not target this proposal")]
+    InvalidVetoTarget,
+    
+    #[msg("Counter-proposal must have required a higher quorum than its target")]
+    InsufficientVetoQuorum,
+    
+    #[msg("Upgrade buffer does not match the buffer committed in the proposal")]
+    UpgradeBufferMismatch,
+    
+    #[msg("Upgrade buffer contents do not match the hash committed in the proposal")]
+    UpgradeBufferHashMismatch,
+    
+    #[msg("Spill account must be the proposer")]
+    InvalidSpillAccount,
+    
+    #[msg("Invalid sanction duration (must be at most 365 days)")]
+    InvalidSanctionDuration,
+    
+    #[msg("Invalid sanction schedule (steps must be ordered and inside the term)")]
+    InvalidSanctionSchedule,
+    
+    #[msg("Proposal country does not match the sanction")]
+    SanctionCountryMismatch,
+    
+    #[msg("Sanction is not active")]
+    SanctionNotActive,
+    
+    #[msg("Country still has an active sanction")]
+    SanctionStillActive,
+    
+    #[msg("Invalid user order (target account and reason code required)")]
+    InvalidUserOrder,
+    
+    #[msg("Voter's attestation is revoked, expired or from an untrusted issuer")]
+    AttestationInvalid,
+    
+    #[msg("Invalid issuer quota (daily cap must be non-zero and at most the weekly cap)")]
+    InvalidIssuerQuota,
+    
+    #[msg("Invalid Core issuer stats account")]
+    InvalidIssuerStats,
+    
+    #[msg("Invalid issuer bond, authority or slash recipient")]
+    InvalidIssuerBond,
+    
+    #[msg("Issuer exit already requested")]
+    IssuerExitPending,
+    
+    #[msg("Issuer bond cannot be withdrawn yet")]
+    BondWithdrawalLocked,
+    
+    #[msg("No unreimbursed delegated claim for this delegate")]
+    InvalidClaimReimbursement,
+    
+    #[msg("Dormant closure period out of range")]
+    InvalidDormantClosurePeriod,
+    
+    #[msg("Child allowance cap above Core's maximum")]
+    InvalidChildAllowanceCap,
+    
+    #[msg("Core user account is not closed as dormant")]
+    UserAccountNotRetired,
 }
 
 // ╔═══════════════════════════════════════════════════════════════════════════╗